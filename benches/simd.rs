@@ -0,0 +1,75 @@
+// compares the array loop Vec4 against the 16 byte aligned Vec4A, run with and without `--features simd`
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use math::{Vec3, Vec3A, Vec4, Vec4A};
+
+const COUNT: usize = 10_000;
+
+fn points() -> Vec<Vec4> {
+    (0..COUNT).map(|i| {
+        let f = i as f32;
+        Vec4::from_xyzw(f.sin(), f.cos(), f*0.001, 1.0)
+    }).collect()
+}
+
+fn dot(c: &mut Criterion) {
+    let vectors = points();
+    let aligned: Vec<Vec4A> = vectors.iter().map(|&v| Vec4A::from(v)).collect();
+    let mut group = c.benchmark_group("dot");
+    group.bench_function("Vec4", |b| b.iter(|| {
+        vectors.windows(2).map(|pair| pair[0].dot(&pair[1])).sum::<f32>()
+    }));
+    group.bench_function("Vec4A", |b| b.iter(|| {
+        aligned.windows(2).map(|pair| pair[0].dot(&pair[1])).sum::<f32>()
+    }));
+    group.finish();
+}
+
+fn normalise(c: &mut Criterion) {
+    let vectors = points();
+    let aligned: Vec<Vec4A> = vectors.iter().map(|&v| Vec4A::from(v)).collect();
+    let narrow: Vec<Vec3> = vectors.iter().map(|v| v.truncate()).collect();
+    let narrow_aligned: Vec<Vec3A> = narrow.iter().map(|&v| Vec3A::from(v)).collect();
+    let mut group = c.benchmark_group("normalise");
+    group.bench_function("Vec4", |b| b.iter(|| {
+        black_box(&vectors).iter().map(|v| v.normalise()).collect::<Vec<_>>()
+    }));
+    group.bench_function("Vec4A", |b| b.iter(|| {
+        black_box(&aligned).iter().map(|v| v.normalise()).collect::<Vec<_>>()
+    }));
+    group.bench_function("Vec3", |b| b.iter(|| {
+        black_box(&narrow).iter().map(|v| v.normalise()).collect::<Vec<_>>()
+    }));
+    group.bench_function("Vec3A", |b| b.iter(|| {
+        black_box(&narrow_aligned).iter().map(|v| v.normalise()).collect::<Vec<_>>()
+    }));
+    group.finish();
+}
+
+// a particle step: position += velocity*dt, velocity -= damping*velocity
+fn integrate(c: &mut Criterion) {
+    let vectors = points();
+    let aligned: Vec<Vec4A> = vectors.iter().map(|&v| Vec4A::from(v)).collect();
+    let mut group = c.benchmark_group("integrate");
+    group.bench_function("Vec4", |b| {
+        let (mut positions, mut velocities) = (vectors.clone(), vectors.clone());
+        b.iter(|| {
+            for (position, velocity) in positions.iter_mut().zip(velocities.iter_mut()) {
+                *position += *velocity*0.016;
+                *velocity -= *velocity*0.01;
+            }
+        })
+    });
+    group.bench_function("Vec4A", |b| {
+        let (mut positions, mut velocities) = (aligned.clone(), aligned.clone());
+        b.iter(|| {
+            for (position, velocity) in positions.iter_mut().zip(velocities.iter_mut()) {
+                *position += *velocity*0.016;
+                *velocity -= *velocity*0.01;
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, dot, normalise, integrate);
+criterion_main!(benches);
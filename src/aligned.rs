@@ -0,0 +1,561 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use paste::paste;
+use crate::{simd, DVec3, DVec4, IVec3, IVec4, Mat3, MatN, MathError, UVec3, UVec4, Vec2, Vec3, Vec4, VecN};
+use crate::vector::{axis_scalar, swizzles};
+
+// 16 byte aligned f32 vectors whose arithmetic goes through the four lane backends in simd.rs.
+// a vec3a is also the size and alignment of a vec3 in std140 and std430 buffers, its fourth lane
+// is padding and always kept at zero so it can be uploaded as is.
+// they have the same api as vec3 and vec4, what has no lane-wise fast path is forwarded
+macro_rules! aligned {
+    ($Vec:ident, $Unaligned:ident, $to_fn:ident, $dim:literal, $from:ident, $(($axis_fn:ident, $axis:ident, $set_axis:ident, $axis_mut:ident => $index:literal)),+) => {
+        #[repr(C, align(16))]
+        #[derive(Clone, Copy, PartialEq, Debug, Default)]
+        pub struct $Vec(pub(crate) [f32; 4]);
+
+        impl $Vec {
+            // every op that computes the padding lane comes through here, even 0 * scalar can be -0.0 or nan
+            #[inline]
+            fn from_lanes(mut lanes: [f32; 4]) -> Self {
+                lanes[$dim..].fill(0.0);
+                $Vec(lanes)
+            }
+            #[inline]
+            pub fn new() -> Self {
+                $Vec([0.0; 4])
+            }
+            #[inline]
+            pub fn $from($($axis: f32),+) -> Self {
+                let mut lanes = [0.0; 4];
+                $(lanes[$index] = $axis;)+
+                $Vec(lanes)
+            }
+            #[inline]
+            pub fn splat(val: f32) -> Self {
+                Self::from_lanes([val; 4])
+            }
+            $(
+                #[inline]
+                pub fn $axis(&self) -> f32 {
+                    self.0[$index]
+                }
+                #[inline]
+                pub fn $set_axis(&mut self, val: f32) {
+                    self.0[$index] = val;
+                }
+                #[inline]
+                pub fn $axis_mut(&mut self) -> &mut f32 {
+                    &mut self.0[$index]
+                }
+                #[inline]
+                pub fn $axis_fn() -> Self {
+                    let mut lanes = [0.0; 4];
+                    lanes[$index] = 1.0;
+                    $Vec(lanes)
+                }
+            )+
+            #[inline]
+            pub fn axis(index: usize) -> Self {
+                let mut lanes = [0.0; 4];
+                lanes[..$dim][index] = 1.0;
+                $Vec(lanes)
+            }
+            #[inline]
+            pub fn as_array(&self) -> &[f32; $dim] {
+                self.0.first_chunk().unwrap()
+            }
+            #[inline]
+            pub fn as_slice(&self) -> &[f32] {
+                &self.0[..$dim]
+            }
+            #[inline]
+            pub fn to_array(&self) -> [f32; $dim] {
+                std::array::from_fn(|i| self.0[i])
+            }
+            #[inline]
+            pub fn $to_fn(&self) -> $Unaligned {
+                VecN(self.to_array())
+            }
+            // the zeroed padding lane drops out of the sum
+            #[inline]
+            pub fn dot(&self, other: &Self) -> f32 {
+                simd::dot(self.0, other.0)
+            }
+            #[inline]
+            pub fn magnitude(&self) -> f32 {
+                self.dot(self).sqrt()
+            }
+            #[inline]
+            pub fn normalise(&self) -> Self {
+                let magnitude = self.magnitude();
+                // chose not to panic, a normalised empty vector is just an empty vector
+                if magnitude == 0.0 {
+                    return Self::new();
+                }
+                *self * (1.0/magnitude)
+            }
+            #[inline]
+            pub fn cos(&self, other: &Self) -> f32 {
+                // chose to panic since there is no meaning in cos(angle) of a vector with zero magnitude
+                assert!(self.magnitude() != 0.0 && other.magnitude() != 0.0, "Magnitude of one of the vectors is zero");
+                self.dot(other)/(self.magnitude()*other.magnitude())
+            }
+            // the error paths are not hot, so they share the checks on the unaligned vectors
+            #[inline]
+            pub fn try_normalise(&self) -> Result<Self, MathError> {
+                self.$to_fn().try_normalise().map(Self::from)
+            }
+            #[inline]
+            pub fn try_cos(&self, other: &Self) -> Result<f32, MathError> {
+                self.$to_fn().try_cos(&other.$to_fn())
+            }
+            #[inline]
+            pub fn angle_between(&self, other: &Self) -> Result<f32, MathError> {
+                self.$to_fn().angle_between(&other.$to_fn())
+            }
+            forward!($to_fn,
+                // componentwise
+                min(other: vec) -> vec;
+                max(other: vec) -> vec;
+                clamp(min: vec, max: vec) -> vec;
+                min_element() -> f32;
+                max_element() -> f32;
+                sum() -> f32;
+                product() -> f32;
+                abs() -> vec;
+                signum() -> vec;
+                floor() -> vec;
+                ceil() -> vec;
+                round() -> vec;
+                fract() -> vec;
+                recip() -> vec;
+                powf(n: f32) -> vec;
+                sqrt() -> vec;
+                exp() -> vec;
+                is_finite() -> bool;
+                is_nan() -> bool;
+                is_normalized() -> bool;
+                // geometric
+                magnitude_squared() -> f32;
+                distance_squared(other: vec) -> f32;
+                distance(other: vec) -> f32;
+                project_onto(other: vec) -> vec;
+                reject_from(other: vec) -> vec;
+                reflect(normal: vec) -> vec;
+                refract(normal: vec, eta: f32) -> vec;
+                with_length(length: f32) -> vec;
+                clamp_length(min: f32, max: f32) -> vec;
+                // interpolate
+                lerp(end: vec, t: f32) -> vec;
+                nlerp(end: vec, t: f32) -> vec;
+                slerp(end: vec, t: f32) -> vec;
+                smoothstep(end: vec, t: f32) -> vec;
+                smootherstep(end: vec, t: f32) -> vec;
+                move_towards(target: vec, max_delta: f32) -> vec;
+            );
+            #[inline]
+            pub fn smooth_damp(&self, target: &Self, velocity: &mut Self, smooth_time: f32, delta_time: f32) -> Self {
+                let mut unaligned = velocity.$to_fn();
+                let output = self.$to_fn().smooth_damp(&target.$to_fn(), &mut unaligned, smooth_time, delta_time);
+                *velocity = Self::from(unaligned);
+                Self::from(output)
+            }
+            #[inline]
+            pub fn map<U>(&self, f: impl Fn(f32) -> U) -> VecN<U, $dim> {
+                self.$to_fn().map(f)
+            }
+            #[inline]
+            pub fn zip_with<U>(&self, other: &Self, f: impl Fn(f32, f32) -> U) -> VecN<U, $dim> {
+                self.$to_fn().zip_with(&other.$to_fn(), f)
+            }
+        }
+
+        impl From<$Unaligned> for $Vec {
+            #[inline]
+            fn from(vector: $Unaligned) -> Self {
+                let mut lanes = [0.0; 4];
+                lanes[..$dim].copy_from_slice(vector.as_slice());
+                $Vec(lanes)
+            }
+        }
+        impl From<$Vec> for $Unaligned {
+            #[inline]
+            fn from(vector: $Vec) -> Self {
+                vector.$to_fn()
+            }
+        }
+        impl From<[f32; $dim]> for $Vec {
+            #[inline]
+            fn from(array: [f32; $dim]) -> Self {
+                Self::from(VecN(array))
+            }
+        }
+        impl From<$Vec> for [f32; $dim] {
+            #[inline]
+            fn from(vector: $Vec) -> Self {
+                vector.to_array()
+            }
+        }
+        impl From<($(axis_scalar!($axis, f32)),+)> for $Vec {
+            #[inline]
+            fn from(($($axis),+): ($(axis_scalar!($axis, f32)),+)) -> Self {
+                Self::$from($($axis),+)
+            }
+        }
+        impl From<$Vec> for ($(axis_scalar!($axis, f32)),+) {
+            #[inline]
+            fn from(vector: $Vec) -> Self {
+                let [$($axis),+] = vector.to_array();
+                ($($axis),+)
+            }
+        }
+        // bounded by the visible lanes, the padding is not a component
+        impl Index<usize> for $Vec {
+            type Output = f32;
+            #[inline]
+            fn index(&self, index: usize) -> &Self::Output {
+                &self.0[..$dim][index]
+            }
+        }
+        impl IndexMut<usize> for $Vec {
+            #[inline]
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                &mut self.0[..$dim][index]
+            }
+        }
+
+        impl Neg for $Vec {
+            type Output = $Vec;
+            #[inline]
+            fn neg(self) -> Self::Output {
+                // -0.0 compares equal to zero but would not upload as zero bytes
+                Self::from_lanes(self.0.map(|val| -val))
+            }
+        }
+        impl Neg for &$Vec {
+            type Output = $Vec;
+            #[inline]
+            fn neg(self) -> Self::Output {
+                -*self
+            }
+        }
+        impl Mul<f32> for $Vec {
+            type Output = $Vec;
+            #[inline]
+            fn mul(self, scalar: f32) -> Self::Output {
+                Self::from_lanes(simd::mul(self.0, [scalar; 4]))
+            }
+        }
+        impl Add<f32> for $Vec {
+            type Output = $Vec;
+            #[inline]
+            fn add(self, scalar: f32) -> Self::Output {
+                Self::from_lanes(simd::add(self.0, [scalar; 4]))
+            }
+        }
+        impl Div<f32> for $Vec {
+            type Output = $Vec;
+            #[inline]
+            fn div(self, scalar: f32) -> Self::Output {
+                Self::from_lanes(simd::div(self.0, [scalar; 4]))
+            }
+        }
+        impl Sub<f32> for $Vec {
+            type Output = $Vec;
+            #[inline]
+            fn sub(self, scalar: f32) -> Self::Output {
+                Self::from_lanes(simd::sub(self.0, [scalar; 4]))
+            }
+        }
+        impl Mul<$Vec> for f32 {
+            type Output = $Vec;
+            #[inline]
+            fn mul(self, vector: $Vec) -> Self::Output {
+                vector * self
+            }
+        }
+        impl Add<$Vec> for f32 {
+            type Output = $Vec;
+            #[inline]
+            fn add(self, vector: $Vec) -> Self::Output {
+                vector + self
+            }
+        }
+
+        aligned_op!($Vec, Add, add, AddAssign, add_assign);
+        aligned_op!($Vec, Sub, sub, SubAssign, sub_assign);
+        aligned_op!($Vec, Mul, mul, MulAssign, mul_assign);
+        aligned_op!($Vec, Div, div, DivAssign, div_assign);
+    };
+}
+
+// `vec` arguments and results are converted to and from the unaligned vector, anything else is passed through
+macro_rules! forward {
+    ($to_fn:ident, $($name:ident($($arg:ident: $kind:tt),*) -> $ret:tt;)+) => {
+        $(
+            #[inline]
+            pub fn $name(&self, $($arg: forward!(@arg_type $kind)),*) -> forward!(@ret_type $ret) {
+                forward!(@ret $ret, self.$to_fn().$name($(forward!(@arg $to_fn, $kind, $arg)),*))
+            }
+        )+
+    };
+    (@arg_type vec) => { &Self };
+    (@arg_type $T:ty) => { $T };
+    (@ret_type vec) => { Self };
+    (@ret_type $T:ty) => { $T };
+    (@arg $to_fn:ident, vec, $arg:ident) => { &$arg.$to_fn() };
+    (@arg $to_fn:ident, $T:tt, $arg:ident) => { $arg };
+    (@ret vec, $output:expr) => { Self::from($output) };
+    (@ret $T:tt, $output:expr) => { $output };
+}
+
+macro_rules! aligned_op {
+    ($Vec:ident, $Op:ident, $op_fn:ident, $OpAssign:ident, $op_assign_fn:ident) => {
+        impl $Op<$Vec> for $Vec {
+            type Output = $Vec;
+            #[inline]
+            fn $op_fn(self, other: $Vec) -> Self::Output {
+                Self::from_lanes(simd::$op_fn(self.0, other.0))
+            }
+        }
+        impl $Op<&$Vec> for &$Vec {
+            type Output = $Vec;
+            #[inline]
+            fn $op_fn(self, other: &$Vec) -> Self::Output {
+                (*self).$op_fn(*other)
+            }
+        }
+        impl $Op<$Vec> for &$Vec {
+            type Output = $Vec;
+            #[inline]
+            fn $op_fn(self, other: $Vec) -> Self::Output {
+                (*self).$op_fn(other)
+            }
+        }
+        impl $Op<&$Vec> for $Vec {
+            type Output = $Vec;
+            #[inline]
+            fn $op_fn(self, other: &$Vec) -> Self::Output {
+                self.$op_fn(*other)
+            }
+        }
+        impl $OpAssign<$Vec> for $Vec {
+            #[inline]
+            fn $op_assign_fn(&mut self, other: $Vec) {
+                *self = (*self).$op_fn(other);
+            }
+        }
+        impl $OpAssign<&$Vec> for $Vec {
+            #[inline]
+            fn $op_assign_fn(&mut self, other: &$Vec) {
+                *self = (*self).$op_fn(*other);
+            }
+        }
+    };
+}
+
+aligned!(Vec3A, Vec3, to_vec3, 3, from_xyz, (x_axis, x, set_x, x_mut => 0), (y_axis, y, set_y, y_mut => 1), (z_axis, z, set_z, z_mut => 2));
+aligned!(Vec4A, Vec4, to_vec4, 4, from_xyzw, (x_axis, x, set_x, x_mut => 0), (y_axis, y, set_y, y_mut => 1), (z_axis, z, set_z, z_mut => 2), (w_axis, w, set_w, w_mut => 3));
+
+swizzles!([impl Vec3A], [Vec2, Vec3A, Vec4A], [x 0, y 1, z 2]);
+swizzles!([impl Vec4A], [Vec2, Vec3A, Vec4A], [x 0, y 1, z 2, w 3]);
+
+impl Vec3A {
+    #[inline]
+    pub fn cross(&self, other: &Self) -> Self {
+        Self::from(self.to_vec3().cross(&other.to_vec3()))
+    }
+    #[inline]
+    pub fn sin(&self, other: &Self) -> f32 {
+        self.to_vec3().sin(&other.to_vec3())
+    }
+    #[inline]
+    pub fn try_sin(&self, other: &Self) -> Result<f32, MathError> {
+        self.to_vec3().try_sin(&other.to_vec3())
+    }
+    forward!(to_vec3,
+        signed_angle(other: vec, up: vec) -> f32;
+        any_orthogonal_vector() -> vec;
+        any_orthonormal_vector() -> vec;
+        as_vec3() -> Vec3;
+        as_dvec3() -> DVec3;
+        as_ivec3() -> IVec3;
+        as_uvec3() -> UVec3;
+    );
+    #[inline]
+    pub fn any_orthonormal_pair(&self) -> (Self, Self) {
+        let (a, b) = self.to_vec3().any_orthonormal_pair();
+        (Self::from(a), Self::from(b))
+    }
+    #[inline]
+    pub fn orthonormalize(a: &Self, b: &Self, c: &Self) -> Result<(Self, Self, Self), MathError> {
+        let (a, b, c) = Vec3::orthonormalize(&a.to_vec3(), &b.to_vec3(), &c.to_vec3())?;
+        Ok((Self::from(a), Self::from(b), Self::from(c)))
+    }
+    #[inline]
+    pub fn extend(&self, w: f32) -> Vec4A {
+        let mut lanes = self.0;
+        lanes[3] = w;
+        Vec4A(lanes)
+    }
+    #[inline]
+    pub fn truncate(&self) -> Vec2 {
+        self.to_vec3().truncate()
+    }
+    #[inline]
+    pub fn xyz0(&self) -> Vec4A {
+        self.extend(0.0)
+    }
+    #[inline]
+    pub fn xyz1(&self) -> Vec4A {
+        self.extend(1.0)
+    }
+    #[inline]
+    pub fn to_point4(&self) -> Vec4A {
+        self.extend(1.0)
+    }
+    #[inline]
+    pub fn to_direction4(&self) -> Vec4A {
+        self.extend(0.0)
+    }
+    #[inline]
+    pub fn project_to_vec2(&self) -> Result<Vec2, MathError> {
+        self.to_vec3().project_to_vec2()
+    }
+}
+
+impl Vec4A {
+    forward!(to_vec4,
+        as_vec4() -> Vec4;
+        as_dvec4() -> DVec4;
+        as_ivec4() -> IVec4;
+        as_uvec4() -> UVec4;
+    );
+    #[inline]
+    pub fn truncate(&self) -> Vec3A {
+        Vec3A::from_lanes(self.0)
+    }
+    #[inline]
+    pub fn project_to_vec3(&self) -> Result<Vec3A, MathError> {
+        self.to_vec4().project_to_vec3().map(Vec3A::from)
+    }
+}
+
+// a mat3 as laid out in std140 and std430 buffers, each column padded to a vec4
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Mat3A(pub(crate) [Vec3A; 3]);
+
+impl Mat3A {
+    pub fn col(&self, index: usize) -> Vec3A {
+        self.0[index]
+    }
+    pub fn to_mat3(&self) -> Mat3 {
+        MatN(self.0.map(|col| col.to_array()))
+    }
+}
+
+impl From<Mat3> for Mat3A {
+    fn from(mat: Mat3) -> Self {
+        Mat3A(mat.0.map(|col| Vec3A::from(VecN(col))))
+    }
+}
+impl From<Mat3A> for Mat3 {
+    fn from(mat: Mat3A) -> Self {
+        mat.to_mat3()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem::{align_of, size_of};
+    use crate::{assert_vec_approx_eq, IVec3, Mat4};
+
+    #[test]
+    fn aligned_layout() {
+        assert_eq!((size_of::<Vec2>(), size_of::<Vec3>(), size_of::<Vec4>()), (8, 12, 16));
+        assert_eq!(size_of::<Mat4>(), 64);
+        assert_eq!((size_of::<Vec3A>(), align_of::<Vec3A>()), (16, 16));
+        assert_eq!((size_of::<Vec4A>(), align_of::<Vec4A>()), (16, 16));
+        assert_eq!((size_of::<Mat3A>(), align_of::<Mat3A>()), (48, 16));
+    }
+    #[test]
+    fn aligned_conversion() {
+        let v = Vec3::from_xyz(1.0, 2.0, 3.0);
+        let aligned = Vec3A::from(v);
+        assert_eq!(aligned.0, [1.0, 2.0, 3.0, 0.0]);
+        assert_eq!(Vec3::from(aligned), v);
+        assert_eq!(Vec3A::splat(2.0).0, [2.0, 2.0, 2.0, 0.0]);
+        assert_eq!(Vec4A::from([1.0, 2.0, 3.0, 4.0]).to_vec4(), Vec4::from_xyzw(1.0, 2.0, 3.0, 4.0));
+        let m = Mat3::from_cols_array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        assert_eq!(Mat3A::from(m).col(1), Vec3A::from_xyz(4.0, 5.0, 6.0));
+        assert_eq!(Mat3::from(Mat3A::from(m)), m);
+    }
+    #[test]
+    fn aligned_padding() {
+        let a = Vec3A::from_xyz(1.0, 2.0, 3.0);
+        assert_eq!((a/0.0).0[3], 0.0);
+        assert_eq!((a + 1.0).0, [2.0, 3.0, 4.0, 0.0]);
+        assert_eq!((a/a).0, [1.0, 1.0, 1.0, 0.0]);
+        assert_eq!((-a).0[3].to_bits(), 0);
+        for scalar in [f32::INFINITY, f32::NAN, -1.0, -0.0] {
+            assert_eq!((a * scalar).0[3].to_bits(), 0, "{scalar}");
+            assert_eq!((scalar * a).0[3].to_bits(), 0, "{scalar}");
+        }
+        assert_eq!(a * f32::INFINITY, a * f32::INFINITY);
+        assert_eq!((-a).normalise().0[3].to_bits(), 0);
+    }
+    #[test]
+    fn aligned_matches_unaligned() {
+        let (a, b) = (Vec4::from_xyzw(1.0, -2.0, 3.0, 0.5), Vec4::from_xyzw(4.0, 5.0, -6.0, 2.0));
+        let (aa, ba) = (Vec4A::from(a), Vec4A::from(b));
+        assert_eq!(aa.dot(&ba), a.dot(&b));
+        assert_eq!((aa + ba).to_vec4(), a + b);
+        assert_eq!((aa - ba).to_vec4(), a - b);
+        assert_eq!((aa*ba).to_vec4(), a*b);
+        assert_eq!((aa/ba).to_vec4(), a/b);
+        assert_eq!((2.0*aa).to_vec4(), 2.0*a);
+        assert_vec_approx_eq!(aa.normalise().to_vec4(), a.normalise());
+        assert_vec_approx_eq!(aa.magnitude(), a.magnitude());
+        let (c, d) = (Vec3::from_xyz(1.0, 2.0, 3.0), Vec3::from_xyz(-1.0, 0.5, 2.0));
+        assert_eq!(Vec3A::from(c).cross(&Vec3A::from(d)).to_vec3(), c.cross(&d));
+        assert_eq!(Vec3A::from(c).dot(&Vec3A::from(d)), c.dot(&d));
+        assert_eq!(Vec3A::new().try_normalise(), Err(MathError::ZeroLength));
+    }
+    #[test]
+    fn aligned_api() {
+        let mut a = Vec3A::from((1.0, -2.0, 3.0));
+        a[0] = 4.0;
+        *a.y_mut() += 1.0;
+        assert_eq!((a[0], a.as_array(), a.as_slice()), (4.0, &[4.0, -1.0, 3.0], &[4.0, -1.0, 3.0][..]));
+        assert_eq!(a.zyx(), Vec3A::from_xyz(3.0, -1.0, 4.0));
+        assert_eq!(a.xy(), Vec2::from_xy(4.0, -1.0));
+        assert_eq!(a.xxzz(), Vec4A::from_xyzw(4.0, 4.0, 3.0, 3.0));
+        assert_eq!(a.extend(1.0).truncate(), a);
+        assert_eq!(a.xyz1().0, [4.0, -1.0, 3.0, 1.0]);
+        assert_eq!(<(f32, f32, f32)>::from(a), (4.0, -1.0, 3.0));
+        assert_eq!(Vec4A::axis(3), Vec4A::w_axis());
+        // forwarded methods keep the aligned type and the zero padding
+        let (v, w) = (Vec3::from_xyz(4.0, -1.0, 3.0), Vec3::from_xyz(0.5, 2.0, -1.0));
+        let b = Vec3A::from(w);
+        assert_eq!(a.lerp(&b, 0.25).to_vec3(), v.lerp(&w, 0.25));
+        assert_eq!(a.project_onto(&b).to_vec3(), v.project_onto(&w));
+        assert_eq!(a.clamp(&Vec3A::splat(0.0), &Vec3A::splat(2.0)), Vec3A::from_xyz(2.0, 0.0, 2.0));
+        assert_eq!((a.abs().0, a.signum().0), ([4.0, 1.0, 3.0, 0.0], [1.0, -1.0, 1.0, 0.0]));
+        assert_eq!((a.distance_squared(&b), a.sum(), a.max_element()), (v.distance_squared(&w), 6.0, 4.0));
+        assert!(a.normalise().is_normalized() && a.is_finite());
+        assert_eq!(a.map(|val| val as i32), IVec3::from_xyz(4, -1, 3));
+        let (mut velocity, mut unaligned_velocity) = (Vec3A::new(), Vec3::new());
+        assert_eq!(a.smooth_damp(&b, &mut velocity, 0.3, 0.1).to_vec3(), v.smooth_damp(&w, &mut unaligned_velocity, 0.3, 0.1));
+        assert_eq!(velocity.to_vec3(), unaligned_velocity);
+        let (x, y) = Vec3A::z_axis().any_orthonormal_pair();
+        assert_vec_approx_eq!(x.cross(&y), Vec3A::z_axis());
+        assert_eq!(Vec4A::from_xyzw(2.0, 4.0, 6.0, 2.0).project_to_vec3(), Ok(Vec3A::from_xyz(1.0, 2.0, 3.0)));
+    }
+    #[test]
+    #[should_panic]
+    fn aligned_index_padding() {
+        let _ = Vec3A::new()[3];
+    }
+}
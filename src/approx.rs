@@ -0,0 +1,167 @@
+use crate::{Float, VecN, MatN, Quaternion, Vec3A, Vec4A};
+
+pub trait ApproxEq {
+    type Epsilon: Copy;
+    fn default_epsilon() -> Self::Epsilon;
+    fn default_max_relative() -> Self::Epsilon;
+    fn default_max_ulps() -> u32 {
+        4
+    }
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool;
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+}
+
+macro_rules! float_approx {
+    ($($T:ident),*) => {
+        $(
+            impl ApproxEq for $T {
+                type Epsilon = $T;
+                fn default_epsilon() -> $T {
+                    $T::EPSILON
+                }
+                fn default_max_relative() -> $T {
+                    $T::EPSILON
+                }
+                fn abs_diff_eq(&self, other: &$T, epsilon: $T) -> bool {
+                    (self - other).abs() <= epsilon
+                }
+                fn relative_eq(&self, other: &$T, epsilon: $T, max_relative: $T) -> bool {
+                    // also handles infinities of the same sign
+                    if self == other {
+                        return true;
+                    }
+                    if !self.is_finite() || !other.is_finite() {
+                        return false;
+                    }
+                    let diff = (self - other).abs();
+                    diff <= epsilon || diff <= self.abs().max(other.abs()) * max_relative
+                }
+                fn ulps_eq(&self, other: &$T, epsilon: $T, max_ulps: u32) -> bool {
+                    if self.abs_diff_eq(other, epsilon) {
+                        return true;
+                    }
+                    if self.is_nan() || other.is_nan() || self.is_sign_negative() != other.is_sign_negative() {
+                        return false;
+                    }
+                    // same sign floats are ordered the same way as their bit patterns
+                    let ulps = (self.to_bits() as i64).wrapping_sub(other.to_bits() as i64).unsigned_abs();
+                    ulps <= max_ulps as u64
+                }
+            }
+        )*
+    };
+}
+
+float_approx!(f32, f64);
+
+// compares the floats of a vector, matrix or quaternion component by component
+macro_rules! component_approx {
+    ($Type:ty, $T:ty, $($generics:tt)*) => {
+        impl<$($generics)*> ApproxEq for $Type where $T: Float + ApproxEq<Epsilon = $T> {
+            type Epsilon = $T;
+            fn default_epsilon() -> $T {
+                <$T>::default_epsilon()
+            }
+            fn default_max_relative() -> $T {
+                <$T>::default_max_relative()
+            }
+            fn abs_diff_eq(&self, other: &Self, epsilon: $T) -> bool {
+                self.as_slice().iter().zip(other.as_slice()).all(|(a, b)| a.abs_diff_eq(b, epsilon))
+            }
+            fn relative_eq(&self, other: &Self, epsilon: $T, max_relative: $T) -> bool {
+                self.as_slice().iter().zip(other.as_slice()).all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+            }
+            fn ulps_eq(&self, other: &Self, epsilon: $T, max_ulps: u32) -> bool {
+                self.as_slice().iter().zip(other.as_slice()).all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+            }
+        }
+    };
+}
+
+component_approx!(VecN<T, N>, T, T, const N: usize);
+component_approx!(MatN<T, N>, T, T, const N: usize);
+component_approx!(Quaternion<T>, T, T);
+component_approx!(Vec3A, f32,);
+component_approx!(Vec4A, f32,);
+
+// `assert_vec_approx_eq!(a, b)` uses relative_eq with the default tolerances,
+// `epsilon = e` switches to abs_diff_eq and `ulps = n` to ulps_eq
+#[macro_export]
+macro_rules! assert_vec_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                fn check<A: $crate::ApproxEq>(left: &A, right: &A) -> bool {
+                    left.relative_eq(right, A::default_epsilon(), A::default_max_relative())
+                }
+                if !check(left, right) {
+                    $crate::assert_vec_approx_eq!(@fail left, right);
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !$crate::ApproxEq::abs_diff_eq(left, right, $epsilon) {
+                    $crate::assert_vec_approx_eq!(@fail left, right);
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, ulps = $ulps:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                fn check<A: $crate::ApproxEq>(left: &A, right: &A, max_ulps: u32) -> bool {
+                    left.ulps_eq(right, A::default_epsilon(), max_ulps)
+                }
+                if !check(left, right, $ulps) {
+                    $crate::assert_vec_approx_eq!(@fail left, right);
+                }
+            }
+        }
+    };
+    (@fail $left:ident, $right:ident) => {
+        panic!("assertion `left ≈ right` failed\n  left: {:?}\n right: {:?}", $left, $right)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ApproxEq, Vec2, Vec3, DVec3, Mat2, Quat};
+
+    #[test]
+    fn approx_float() {
+        assert!(1.0f32.abs_diff_eq(&1.05, 0.1));
+        assert!(!1.0f32.abs_diff_eq(&1.2, 0.1));
+        assert!(1000.0f64.relative_eq(&1000.1, 0.0, 1e-3));
+        assert!(!1.0f64.relative_eq(&1.1, 0.0, 1e-3));
+        assert!(f32::INFINITY.relative_eq(&f32::INFINITY, 0.0, 0.0));
+        assert!(!f32::NAN.relative_eq(&f32::NAN, 1.0, 1.0));
+        let next = f32::from_bits(1.0f32.to_bits() + 2);
+        assert!(1.0f32.ulps_eq(&next, 0.0, 2));
+        assert!(!1.0f32.ulps_eq(&next, 0.0, 1));
+        assert!(!1.0f32.ulps_eq(&-1.0, 0.0, u32::MAX));
+    }
+    #[test]
+    fn approx_vector() {
+        let v = Vec3::from_xyz(0.1, 0.2, 0.3);
+        assert!((v + Vec3::splat(1e-7)).abs_diff_eq(&v, 1e-6));
+        assert!(!Vec2::from_xy(1.0, 2.0).abs_diff_eq(&Vec2::from_xy(1.0, 2.1), 1e-6));
+        assert!(DVec3::splat(0.1 + 0.2).ulps_eq(&DVec3::splat(0.3), 0.0, 1));
+        assert!(Mat2::identity().relative_eq(&(Mat2::identity() * 1.0000001), 0.0, 1e-6));
+        assert!(Quat::identity().abs_diff_eq(&Quat::from_rotation_x(1e-7), 1e-6));
+    }
+    #[test]
+    fn approx_macro() {
+        assert_vec_approx_eq!(0.1f64 + 0.2, 0.3);
+        assert_vec_approx_eq!(Vec2::from_xy(3.0, 4.0).normalise(), Vec2::from_xy(0.6, 0.8), ulps = 2);
+        assert_vec_approx_eq!(Vec3::splat(1.0), Vec3::splat(1.001), epsilon = 0.01);
+    }
+    #[test]
+    #[should_panic = "assertion `left ≈ right` failed"]
+    fn approx_macro_fail() {
+        assert_vec_approx_eq!(Vec3::splat(1.0), Vec3::splat(1.1), epsilon = 0.01);
+    }
+}
@@ -0,0 +1,210 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::{Float, Scalar, Signed, VecN};
+
+// L vec3s stored structure of arrays, [xs, ys, zs], so every method below is a loop over
+// fixed length lanes which the compiler turns into packed instructions
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Vec3xN<T, const L: usize>(pub(crate) [[T; L]; 3]);
+
+pub type Vec3x4 = Vec3xN<f32, 4>;
+pub type Vec3x8 = Vec3xN<f32, 8>;
+pub type DVec3x4 = Vec3xN<f64, 4>;
+
+impl<T: Scalar, const L: usize> Default for Vec3xN<T, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Scalar, const L: usize> Vec3xN<T, L> {
+    pub fn new() -> Self {
+        Vec3xN([[T::ZERO; L]; 3])
+    }
+    pub fn splat(vector: VecN<T, 3>) -> Self {
+        Vec3xN(vector.0.map(|val| [val; L]))
+    }
+    pub fn from_array(vectors: [VecN<T, 3>; L]) -> Self {
+        Vec3xN(std::array::from_fn(|axis| std::array::from_fn(|lane| vectors[lane].0[axis])))
+    }
+    pub fn to_array(&self) -> [VecN<T, 3>; L] {
+        std::array::from_fn(|lane| self.lane(lane))
+    }
+    pub fn lane(&self, lane: usize) -> VecN<T, 3> {
+        VecN(self.0.map(|axis| axis[lane]))
+    }
+    pub fn set_lane(&mut self, lane: usize, vector: VecN<T, 3>) {
+        for axis in 0..3 {
+            self.0[axis][lane] = vector.0[axis];
+        }
+    }
+    pub fn x(&self) -> &[T; L] {
+        &self.0[0]
+    }
+    pub fn y(&self) -> &[T; L] {
+        &self.0[1]
+    }
+    pub fn z(&self) -> &[T; L] {
+        &self.0[2]
+    }
+    // the last batch is padded with zero vectors when the length isn't a multiple of L
+    pub fn from_slice(vectors: &[VecN<T, 3>]) -> Vec<Self> {
+        vectors.chunks(L).map(|chunk| {
+            let mut batch = Self::new();
+            chunk.iter().enumerate().for_each(|(lane, vector)| batch.set_lane(lane, *vector));
+            batch
+        }).collect()
+    }
+    // writes as many vectors as fit in out, so the padding of the last batch can be dropped
+    pub fn write_to_slice(batches: &[Self], out: &mut [VecN<T, 3>]) {
+        out.chunks_mut(L).zip(batches).for_each(|(chunk, batch)| {
+            chunk.iter_mut().enumerate().for_each(|(lane, vector)| *vector = batch.lane(lane));
+        });
+    }
+    pub fn to_vec(batches: &[Self], len: usize) -> Vec<VecN<T, 3>> {
+        let mut out = vec![VecN::new(); len];
+        Self::write_to_slice(batches, &mut out);
+        out
+    }
+    fn lanes(f: impl Fn(usize) -> T) -> [T; L] {
+        std::array::from_fn(f)
+    }
+    pub fn dot(&self, other: &Self) -> [T; L] {
+        let (a, b) = (&self.0, &other.0);
+        Self::lanes(|i| a[0][i]*b[0][i] + a[1][i]*b[1][i] + a[2][i]*b[2][i])
+    }
+}
+
+impl<T: Float, const L: usize> Vec3xN<T, L> {
+    pub fn magnitude(&self) -> [T; L] {
+        self.dot(self).map(|val| val.sqrt())
+    }
+    pub fn normalise(&self) -> Self {
+        // chose not to panic, zero lanes stay zero like VecN::normalise
+        let scale = self.magnitude().map(|magnitude| if magnitude == T::ZERO { T::ZERO } else { T::ONE/magnitude });
+        *self * scale
+    }
+}
+
+impl<T: Signed, const L: usize> Vec3xN<T, L> {
+    pub fn cross(&self, other: &Self) -> Self {
+        let (a, b) = (&self.0, &other.0);
+        Vec3xN([
+            Self::lanes(|i| a[1][i]*b[2][i] - a[2][i]*b[1][i]),
+            Self::lanes(|i| a[2][i]*b[0][i] - a[0][i]*b[2][i]),
+            Self::lanes(|i| a[0][i]*b[1][i] - a[1][i]*b[0][i]),
+        ])
+    }
+}
+
+impl<T: Scalar, const L: usize> From<[VecN<T, 3>; L]> for Vec3xN<T, L> {
+    fn from(vectors: [VecN<T, 3>; L]) -> Self {
+        Self::from_array(vectors)
+    }
+}
+impl<T: Scalar, const L: usize> From<Vec3xN<T, L>> for [VecN<T, 3>; L] {
+    fn from(batch: Vec3xN<T, L>) -> Self {
+        batch.to_array()
+    }
+}
+
+impl<T: Signed, const L: usize> Neg for Vec3xN<T, L> {
+    type Output = Vec3xN<T, L>;
+    fn neg(self) -> Self::Output {
+        Vec3xN(self.0.map(|axis| axis.map(|val| -val)))
+    }
+}
+
+// the same scalar for every lane, or one scalar per lane, e.g. the result of magnitude
+macro_rules! batch_scalar_op {
+    ($Op:ident, $op_fn:ident, $op:tt) => {
+        impl<T: Scalar, const L: usize> $Op<T> for Vec3xN<T, L> {
+            type Output = Vec3xN<T, L>;
+            fn $op_fn(self, scalar: T) -> Self::Output {
+                Vec3xN(self.0.map(|axis| axis.map(|val| val $op scalar)))
+            }
+        }
+        impl<T: Scalar, const L: usize> $Op<[T; L]> for Vec3xN<T, L> {
+            type Output = Vec3xN<T, L>;
+            fn $op_fn(self, scalars: [T; L]) -> Self::Output {
+                Vec3xN(self.0.map(|axis| std::array::from_fn(|i| axis[i] $op scalars[i])))
+            }
+        }
+    };
+}
+
+batch_scalar_op!(Mul, mul, *);
+batch_scalar_op!(Add, add, +);
+batch_scalar_op!(Div, div, /);
+batch_scalar_op!(Sub, sub, -);
+
+macro_rules! batch_op {
+    ($Op:ident, $op_fn:ident, $OpAssign:ident, $op_assign_fn:ident, $op:tt) => {
+        impl<T: Scalar, const L: usize> $Op<Vec3xN<T, L>> for Vec3xN<T, L> {
+            type Output = Vec3xN<T, L>;
+            fn $op_fn(self, other: Vec3xN<T, L>) -> Self::Output {
+                Vec3xN(std::array::from_fn(|axis| std::array::from_fn(|i| self.0[axis][i] $op other.0[axis][i])))
+            }
+        }
+        impl<T: Scalar, const L: usize> $Op<&Vec3xN<T, L>> for &Vec3xN<T, L> {
+            type Output = Vec3xN<T, L>;
+            fn $op_fn(self, other: &Vec3xN<T, L>) -> Self::Output {
+                *self $op *other
+            }
+        }
+        impl<T: Scalar, const L: usize> $OpAssign<Vec3xN<T, L>> for Vec3xN<T, L> {
+            fn $op_assign_fn(&mut self, other: Vec3xN<T, L>) {
+                *self = *self $op other;
+            }
+        }
+        impl<T: Scalar, const L: usize> $OpAssign<&Vec3xN<T, L>> for Vec3xN<T, L> {
+            fn $op_assign_fn(&mut self, other: &Vec3xN<T, L>) {
+                *self = *self $op *other;
+            }
+        }
+    };
+}
+
+batch_op!(Add, add, AddAssign, add_assign, +);
+batch_op!(Sub, sub, SubAssign, sub_assign, -);
+// component-wise (hadamard) product, use dot for the inner product
+batch_op!(Mul, mul, MulAssign, mul_assign, *);
+batch_op!(Div, div, DivAssign, div_assign, /);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_vec_approx_eq, Vec3};
+
+    fn points() -> Vec<Vec3> {
+        (0..11).map(|i| Vec3::from_xyz(i as f32, 1.0 - i as f32, 0.5*i as f32)).collect()
+    }
+
+    #[test]
+    fn batch_roundtrip() {
+        let points = points();
+        let batches = Vec3x4::from_slice(&points);
+        assert_eq!(batches.len(), 3);
+        assert_eq!(batches[2].lane(3), Vec3::new());
+        assert_eq!(batches[1].x(), &[4.0, 5.0, 6.0, 7.0]);
+        assert_eq!(Vec3x4::to_vec(&batches, points.len()), points);
+        let array = [points[0], points[1], points[2], points[3]];
+        assert_eq!(<[Vec3; 4]>::from(Vec3x4::from(array)), array);
+    }
+    #[test]
+    fn batch_matches_vectors() {
+        let points = points();
+        let (a, b) = (Vec3x8::from_slice(&points[..8])[0], Vec3x8::splat(Vec3::from_xyz(1.0, 2.0, -3.0)));
+        let (dot, magnitude, normalised, cross) = (a.dot(&b), a.magnitude(), a.normalise(), a.cross(&b));
+        for lane in 0..8 {
+            let (u, v) = (a.lane(lane), b.lane(lane));
+            assert_eq!(dot[lane], u.dot(&v));
+            assert_vec_approx_eq!(magnitude[lane], u.magnitude());
+            assert_vec_approx_eq!(normalised.lane(lane), u.normalise());
+            assert_eq!(cross.lane(lane), u.cross(&v));
+            assert_eq!((a + b).lane(lane), u + v);
+            assert_eq!((-a*2.0).lane(lane), -u*2.0);
+        }
+        assert_eq!(Vec3x4::new().normalise(), Vec3x4::new());
+    }
+}
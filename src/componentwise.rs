@@ -0,0 +1,132 @@
+use crate::{Float, Scalar, Signed, VecN};
+
+impl<T: Scalar, const N: usize> VecN<T, N> {
+    pub fn map<U>(&self, f: impl Fn(T) -> U) -> VecN<U, N> {
+        VecN(self.0.map(f))
+    }
+    pub fn zip_with<U>(&self, other: &Self, f: impl Fn(T, T) -> U) -> VecN<U, N> {
+        VecN(std::array::from_fn(|i| f(self.0[i], other.0[i])))
+    }
+    // PartialOrd only, so a NaN component compares false and the other side is kept
+    pub fn min(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| if b < a { b } else { a })
+    }
+    pub fn max(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| if b > a { b } else { a })
+    }
+    pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+        assert!((0..N).all(|i| min.0[i] <= max.0[i]), "Minimum is larger than the maximum");
+        self.max(min).min(max)
+    }
+    pub fn min_element(&self) -> T {
+        self.0.into_iter().reduce(|a, b| if b < a { b } else { a }).unwrap_or(T::ZERO)
+    }
+    pub fn max_element(&self) -> T {
+        self.0.into_iter().reduce(|a, b| if b > a { b } else { a }).unwrap_or(T::ZERO)
+    }
+    pub fn sum(&self) -> T {
+        self.0.into_iter().sum()
+    }
+    pub fn product(&self) -> T {
+        self.0.into_iter().fold(T::ONE, |product, val| product * val)
+    }
+}
+
+// integer signum is 0 at zero, float signum is ±1 and keeps the sign of -0.0
+impl<T: Signed, const N: usize> VecN<T, N> {
+    pub fn abs(&self) -> Self {
+        self.map(T::abs)
+    }
+    pub fn signum(&self) -> Self {
+        self.map(T::signum)
+    }
+}
+
+impl<T: Float, const N: usize> VecN<T, N> {
+    pub fn floor(&self) -> Self {
+        self.map(T::floor)
+    }
+    pub fn ceil(&self) -> Self {
+        self.map(T::ceil)
+    }
+    // halfway cases round away from zero
+    pub fn round(&self) -> Self {
+        self.map(T::round)
+    }
+    // same sign as the component, like f32::fract
+    pub fn fract(&self) -> Self {
+        self.map(T::fract)
+    }
+    pub fn recip(&self) -> Self {
+        self.map(T::recip)
+    }
+    pub fn powf(&self, n: T) -> Self {
+        self.map(|val| val.powf(n))
+    }
+    pub fn sqrt(&self) -> Self {
+        self.map(T::sqrt)
+    }
+    pub fn exp(&self) -> Self {
+        self.map(T::exp)
+    }
+    pub fn is_finite(&self) -> bool {
+        self.0.iter().all(|val| val.is_finite())
+    }
+    pub fn is_nan(&self) -> bool {
+        self.0.iter().any(|val| val.is_nan())
+    }
+    // within sqrt(epsilon) of unit length, loose enough for the result of normalise
+    pub fn is_normalized(&self) -> bool {
+        (self.magnitude_squared() - T::ONE).abs() <= T::EPSILON.sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IVec3, UVec2, Vec2, Vec3, Vec4};
+
+    #[test]
+    fn componentwise_min_max() {
+        let (a, b) = (IVec3::from_xyz(1, 5, -3), IVec3::from_xyz(2, -4, -3));
+        assert_eq!(a.min(&b), IVec3::from_xyz(1, -4, -3));
+        assert_eq!(a.max(&b), IVec3::from_xyz(2, 5, -3));
+        assert_eq!(a.clamp(&IVec3::splat(0), &IVec3::splat(2)), IVec3::from_xyz(1, 2, 0));
+        assert_eq!((a.min_element(), a.max_element()), (-3, 5));
+        assert_eq!((a.sum(), a.product()), (3, -15));
+        assert_eq!(UVec2::from_xy(3, 4).product(), 12);
+    }
+    #[test]
+    fn componentwise_signed() {
+        let v = IVec3::from_xyz(-3, 0, 7);
+        assert_eq!(v.abs(), IVec3::from_xyz(3, 0, 7));
+        assert_eq!(v.signum(), IVec3::from_xyz(-1, 0, 1));
+    }
+    #[test]
+    #[should_panic(expected = "Minimum is larger than the maximum")]
+    fn componentwise_clamp_inverted() {
+        Vec2::new().clamp(&Vec2::splat(1.0), &Vec2::splat(0.0));
+    }
+    #[test]
+    fn componentwise_float() {
+        let v = Vec4::from_xyzw(-1.5, 2.25, 0.5, -0.0);
+        assert_eq!(v.abs(), Vec4::from_xyzw(1.5, 2.25, 0.5, 0.0));
+        assert_eq!(v.signum(), Vec4::from_xyzw(-1.0, 1.0, 1.0, -1.0));
+        assert_eq!(v.floor(), Vec4::from_xyzw(-2.0, 2.0, 0.0, -0.0));
+        assert_eq!(v.ceil(), Vec4::from_xyzw(-1.0, 3.0, 1.0, -0.0));
+        assert_eq!(v.round(), Vec4::from_xyzw(-2.0, 2.0, 1.0, -0.0));
+        assert_eq!(v.fract(), Vec4::from_xyzw(-0.5, 0.25, 0.5, 0.0));
+        assert_eq!(Vec2::from_xy(4.0, 0.5).recip(), Vec2::from_xy(0.25, 2.0));
+        assert_eq!(Vec2::from_xy(4.0, 9.0).sqrt(), Vec2::from_xy(2.0, 3.0));
+        assert_eq!(Vec2::from_xy(2.0, 3.0).powf(2.0), Vec2::from_xy(4.0, 9.0));
+        assert_eq!(Vec2::new().exp(), Vec2::splat(1.0));
+    }
+    #[test]
+    fn componentwise_checks_and_map() {
+        assert!(Vec3::x_axis().is_normalized() && Vec3::splat(1.0).normalise().is_normalized());
+        assert!(!Vec3::splat(1.0).is_normalized());
+        assert!(Vec3::from_xyz(0.0, f32::NAN, 0.0).is_nan() && !Vec3::new().is_nan());
+        assert!(!Vec3::from_xyz(f32::INFINITY, 0.0, 0.0).is_finite() && Vec3::new().is_finite());
+        assert_eq!(Vec3::from_xyz(1.2, 2.7, -3.5).map(|val| val as i32), IVec3::from_xyz(1, 2, -3));
+        assert_eq!(IVec3::splat(2).zip_with(&IVec3::from_xyz(1, 2, 3), |a, b| a.pow(b as u32)), IVec3::from_xyz(2, 4, 8));
+    }
+}
@@ -0,0 +1,303 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use crate::predicates::{incircle, orient2d};
+use crate::{DVec2, Float, Polygon, Rect, VecN};
+
+// marks the vertex at infinity, every hull edge gets a ghost triangle (b, a, GHOST) on its
+// outside so the mesh is closed and points outside the hull need no special casing
+const GHOST: usize = usize::MAX;
+
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Triangulation {
+    // counter-clockwise index triples into the input points
+    pub triangles: Vec<[usize; 3]>,
+    // the triangle across the edge opposite each vertex, None on the hull
+    pub neighbours: Vec<[Option<usize>; 3]>,
+}
+
+struct Mesh {
+    points: Vec<DVec2>,
+    triangles: Vec<[usize; 3]>,
+    neighbours: Vec<[usize; 3]>,
+    alive: Vec<bool>,
+    free: Vec<usize>,
+}
+
+impl Mesh {
+    fn edge(&self, triangle: usize, opposite: usize) -> (usize, usize) {
+        let vertices = self.triangles[triangle];
+        (vertices[(opposite + 1) % 3], vertices[(opposite + 2) % 3])
+    }
+    fn is_ghost(&self, triangle: usize) -> bool {
+        self.triangles[triangle][2] == GHOST
+    }
+    // whether point breaks the empty circumcircle of the triangle. for a ghost triangle that is the
+    // open half plane outside its hull edge, plus the edge itself so points landing on the hull
+    // split it
+    fn in_circumcircle(&self, triangle: usize, point: usize) -> bool {
+        let [a, b, c] = self.triangles[triangle];
+        let p = self.points[point];
+        if c != GHOST {
+            return incircle(self.points[a], self.points[b], self.points[c], p) > 0.0;
+        }
+        let (a, b) = (self.points[a], self.points[b]);
+        let side = orient2d(a, b, p);
+        side > 0.0 || (side == 0.0 && (p - a).dot(&(p - b)) < 0.0)
+    }
+    fn add(&mut self, vertices: [usize; 3]) -> usize {
+        // ghosts always keep the vertex at infinity last
+        let vertices = match vertices.iter().position(|&v| v == GHOST) {
+            Some(0) => [vertices[1], vertices[2], GHOST],
+            Some(1) => [vertices[2], vertices[0], GHOST],
+            _ => vertices,
+        };
+        if let Some(slot) = self.free.pop() {
+            self.triangles[slot] = vertices;
+            self.alive[slot] = true;
+            return slot;
+        }
+        self.triangles.push(vertices);
+        self.neighbours.push([GHOST; 3]);
+        self.alive.push(true);
+        self.triangles.len() - 1
+    }
+    // visibility walk from start towards the point, ending in a triangle whose circumcircle it breaks
+    fn locate(&self, start: usize, point: usize) -> usize {
+        let p = self.points[point];
+        // a ghost only counts once the walk has crossed its hull edge, so start from the inside
+        let mut current = if self.is_ghost(start) { self.neighbours[start][2] } else { start };
+        'walk: loop {
+            if self.is_ghost(current) {
+                return current;
+            }
+            for opposite in 0..3 {
+                let (a, b) = self.edge(current, opposite);
+                if orient2d(self.points[a], self.points[b], p) < 0.0 {
+                    current = self.neighbours[current][opposite];
+                    continue 'walk;
+                }
+            }
+            // inside or on the boundary of a real triangle
+            return current;
+        }
+    }
+    // bowyer-watson: remove every triangle whose circumcircle holds the point, then fan the
+    // star shaped hole from the point. returns one of the new triangles
+    fn insert(&mut self, start: usize, point: usize) -> usize {
+        let first = self.locate(start, point);
+        let (mut bad, mut stack) = (vec![first], vec![first]);
+        let mut boundary = Vec::new();
+        while let Some(current) = stack.pop() {
+            for opposite in 0..3 {
+                let neighbour = self.neighbours[current][opposite];
+                if bad.contains(&neighbour) {
+                    continue;
+                }
+                if self.in_circumcircle(neighbour, point) {
+                    bad.push(neighbour);
+                    stack.push(neighbour);
+                } else {
+                    boundary.push((self.edge(current, opposite), neighbour));
+                }
+            }
+        }
+        for &triangle in bad.iter() {
+            self.alive[triangle] = false;
+            self.free.push(triangle);
+        }
+        let mut edges = HashMap::new();
+        let mut created = Vec::with_capacity(boundary.len());
+        for ((a, b), outside) in boundary {
+            let triangle = self.add([a, b, point]);
+            created.push(triangle);
+            for opposite in 0..3 {
+                let (u, v) = self.edge(triangle, opposite);
+                if (u, v) == (a, b) {
+                    self.neighbours[triangle][opposite] = outside;
+                    let back = (0..3).find(|&i| self.edge(outside, i) == (b, a)).expect("boundary edges are shared");
+                    self.neighbours[outside][back] = triangle;
+                } else if let Some((other, other_opposite)) = edges.remove(&(v, u)) {
+                    self.neighbours[triangle][opposite] = other;
+                    self.neighbours[other][other_opposite] = triangle;
+                } else {
+                    edges.insert((u, v), (triangle, opposite));
+                }
+            }
+        }
+        *created.iter().find(|&&triangle| !self.is_ghost(triangle)).unwrap_or(&created[0])
+    }
+}
+
+// bowyer-watson delaunay triangulation built on the exact incircle and orient2d predicates. cocircular
+// points are split arbitrarily but consistently. duplicates after the first and non-finite points are
+// left out, and fewer than three non-collinear points gives an empty triangulation
+pub fn delaunay2<T: Float>(points: &[VecN<T, 2>]) -> Triangulation
+where
+    VecN<T, 2>: Into<DVec2>,
+{
+    let converted: Vec<DVec2> = points.iter().map(|&point| point.into()).collect();
+    let mut order: Vec<usize> = (0..points.len()).filter(|&i| converted[i].is_finite()).collect();
+    // sorting keeps each insertion close to the last, so the walks stay short
+    order.sort_by(|&a, &b| {
+        let (a, b) = (converted[a], converted[b]);
+        a.x().partial_cmp(&b.x()).unwrap_or(Ordering::Equal).then(a.y().partial_cmp(&b.y()).unwrap_or(Ordering::Equal))
+    });
+    order.dedup_by(|a, b| converted[*a] == converted[*b]);
+    let Some(third) = order.iter().skip(2).position(|&i| orient2d(converted[order[0]], converted[order[1]], converted[i]) != 0.0) else {
+        return Triangulation::default();
+    };
+    let third = order.remove(third + 2);
+    let (a, b) = (order[0], order[1]);
+    let (a, b) = if orient2d(converted[a], converted[b], converted[third]) > 0.0 { (a, b) } else { (b, a) };
+
+    let mut mesh = Mesh { points: converted, triangles: Vec::new(), neighbours: Vec::new(), alive: Vec::new(), free: Vec::new() };
+    let inner = mesh.add([a, b, third]);
+    let ghosts = [mesh.add([b, a, GHOST]), mesh.add([third, b, GHOST]), mesh.add([a, third, GHOST])];
+    // the ghost on the edge opposite vertex i, and the ghosts meet each other around the hull
+    mesh.neighbours[inner] = [ghosts[1], ghosts[2], ghosts[0]];
+    mesh.neighbours[ghosts[0]] = [ghosts[2], ghosts[1], inner];
+    mesh.neighbours[ghosts[1]] = [ghosts[0], ghosts[2], inner];
+    mesh.neighbours[ghosts[2]] = [ghosts[1], ghosts[0], inner];
+    let mut last = inner;
+    for &point in order.iter().skip(2) {
+        last = mesh.insert(last, point);
+    }
+
+    let real: Vec<usize> = (0..mesh.triangles.len()).filter(|&t| mesh.alive[t] && !mesh.is_ghost(t)).collect();
+    let index: HashMap<usize, usize> = real.iter().enumerate().map(|(new, &old)| (old, new)).collect();
+    Triangulation {
+        triangles: real.iter().map(|&t| mesh.triangles[t]).collect(),
+        neighbours: real.iter().map(|&t| mesh.neighbours[t].map(|n| index.get(&n).copied())).collect(),
+    }
+}
+
+impl Triangulation {
+    // the voronoi cell of every point, clipped to bounds and indexed like the points. each cell is
+    // the bounds cut by the bisectors with the point's delaunay neighbours, so unbounded cells on the
+    // hull need nothing special. points left out of the triangulation get an empty cell, unless
+    // it is empty because everything is collinear, then every distinct point is a neighbour
+    pub fn voronoi<T: Float>(&self, points: &[VecN<T, 2>], bounds: &Rect<T>) -> Vec<Polygon<T>> {
+        let mut neighbours: Vec<Vec<usize>> = vec![Vec::new(); points.len()];
+        for &[a, b, c] in self.triangles.iter() {
+            for (from, to) in [(a, b), (b, c), (c, a)] {
+                neighbours[from].push(to);
+                neighbours[to].push(from);
+            }
+        }
+        for around in neighbours.iter_mut() {
+            around.sort_unstable();
+            around.dedup();
+        }
+        if self.triangles.is_empty() {
+            for i in (0..points.len()).filter(|&i| points[i].is_finite() && !points[..i].contains(&points[i])) {
+                neighbours[i] = (0..points.len()).filter(|&j| points[j].is_finite() && points[j] != points[i]).collect();
+            }
+        }
+        let two = T::ONE + T::ONE;
+        neighbours.iter().enumerate().map(|(site, around)| {
+            if around.is_empty() {
+                return Polygon::default();
+            }
+            around.iter().fold(bounds.to_polygon(), |cell, &other| {
+                let midpoint = (points[site] + points[other]) / two;
+                cell.clip_half_plane(&midpoint, &(points[other] - points[site]))
+            })
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_vec_approx_eq, convex_hull2, Rect, Vec2};
+
+    fn random_points(count: usize) -> Vec<Vec2> {
+        let mut seed = 99u64;
+        let mut rand = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 40) as f32 / (1u64 << 24) as f32
+        };
+        (0..count).map(|_| Vec2::from_xy(rand(), rand())).collect()
+    }
+    fn check(points: &[Vec2], triangulation: &Triangulation) {
+        for (t, &[a, b, c]) in triangulation.triangles.iter().enumerate() {
+            assert!(orient2d(points[a], points[b], points[c]) > 0.0);
+            // empty circumcircles
+            assert!(points.iter().filter(|p| p.is_finite()).all(|&p| incircle(points[a], points[b], points[c], p) <= 0.0));
+            for (opposite, neighbour) in triangulation.neighbours[t].iter().enumerate() {
+                let edge = ([a, b, c][(opposite + 1) % 3], [a, b, c][(opposite + 2) % 3]);
+                if let Some(n) = *neighbour {
+                    let back = triangulation.triangles[n];
+                    assert!((0..3).any(|i| (back[(i + 1) % 3], back[(i + 2) % 3]) == (edge.1, edge.0)));
+                    assert!(triangulation.neighbours[n].contains(&Some(t)));
+                }
+            }
+        }
+    }
+    fn area(points: &[Vec2], triangulation: &Triangulation) -> f32 {
+        triangulation.triangles.iter().map(|&[a, b, c]| (points[b] - points[a]).perp_dot(&(points[c] - points[a])) / 2.0).sum()
+    }
+
+    #[test]
+    fn delaunay_random() {
+        let points = random_points(300);
+        let triangulation = delaunay2(&points);
+        check(&points, &triangulation);
+        // euler: 2n - h - 2 triangles for n points with h on the hull
+        let hull = convex_hull2(&points).len();
+        assert_eq!(triangulation.triangles.len(), 2 * points.len() - hull - 2);
+        assert_eq!(triangulation.neighbours.iter().flatten().filter(|n| n.is_none()).count(), hull);
+    }
+    #[test]
+    fn delaunay_lattice() {
+        // every square is cocircular and every edge of the grid collinear
+        let points: Vec<Vec2> = (0..36).map(|i| Vec2::from_xy((i % 6) as f32, (i / 6) as f32)).collect();
+        let triangulation = delaunay2(&points);
+        check(&points, &triangulation);
+        assert_eq!(triangulation.triangles.len(), 50);
+        assert_eq!(area(&points, &triangulation), 25.0);
+    }
+    #[test]
+    fn delaunay_degenerate() {
+        assert_eq!(delaunay2::<f32>(&[]), Triangulation::default());
+        let line: Vec<Vec2> = (0..5).map(|i| Vec2::splat(i as f32)).collect();
+        assert_eq!(delaunay2(&line), Triangulation::default());
+        let points = [Vec2::new(), Vec2::x_axis(), Vec2::new(), Vec2::y_axis(), Vec2::splat(f32::NAN), Vec2::splat(1.0)];
+        let triangulation = delaunay2(&points);
+        check(&points, &triangulation);
+        assert_eq!(triangulation.triangles.len(), 2);
+        assert!(triangulation.triangles.iter().flatten().all(|&i| i != 2 && i != 4));
+        // points along a hull edge split it
+        let fan = [Vec2::new(), Vec2::from_xy(4.0, 0.0), Vec2::from_xy(2.0, 3.0), Vec2::from_xy(1.0, 0.0), Vec2::from_xy(3.0, 0.0)];
+        let triangulation = delaunay2(&fan);
+        check(&fan, &triangulation);
+        assert_eq!((triangulation.triangles.len(), area(&fan, &triangulation)), (3, 6.0));
+    }
+    #[test]
+    fn voronoi_cells() {
+        let bounds = Rect::new(Vec2::new(), Vec2::splat(4.0));
+        let points = [Vec2::splat(1.0), Vec2::from_xy(3.0, 1.0), Vec2::splat(3.0), Vec2::from_xy(1.0, 3.0)];
+        let cells = delaunay2(&points).voronoi(&points, &bounds);
+        for (cell, point) in cells.iter().zip(points) {
+            assert_vec_approx_eq!(cell.area(), 4.0);
+            assert!(cell.contains_point(&point));
+        }
+        let points = random_points(100).into_iter().map(|p| p * 4.0).collect::<Vec<_>>();
+        let cells = delaunay2(&points).voronoi(&points, &bounds);
+        assert_vec_approx_eq!(cells.iter().map(|cell| cell.area()).sum::<f32>(), 16.0, epsilon = 1e-3);
+        for (site, cell) in cells.iter().enumerate() {
+            // every cell vertex is at least as close to its own site as to any other
+            for vertex in cell.vertices.iter() {
+                let own = vertex.distance(&points[site]);
+                assert!(points.iter().all(|p| vertex.distance(p) >= own - 1e-4));
+            }
+        }
+    }
+    #[test]
+    fn voronoi_collinear() {
+        let bounds = Rect::new(Vec2::new(), Vec2::from_xy(3.0, 1.0));
+        let points = [Vec2::from_xy(0.5, 0.5), Vec2::from_xy(1.5, 0.5), Vec2::from_xy(2.5, 0.5), Vec2::from_xy(1.5, 0.5)];
+        let cells = delaunay2(&points).voronoi(&points, &bounds);
+        assert_eq!(cells.iter().map(|cell| cell.area()).collect::<Vec<_>>(), vec![1.0, 1.0, 1.0, 0.0]);
+    }
+}
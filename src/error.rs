@@ -0,0 +1,22 @@
+use std::fmt;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MathError {
+    ZeroLength,
+    NaN,
+    NonFinite,
+    ZeroW,
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MathError::ZeroLength => write!(f, "Magnitude of the vector is zero"),
+            MathError::NaN => write!(f, "Vector has a NaN component"),
+            MathError::NonFinite => write!(f, "Vector has an infinite component"),
+            MathError::ZeroW => write!(f, "Homogeneous w component is zero"),
+        }
+    }
+}
+
+impl std::error::Error for MathError {}
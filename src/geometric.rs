@@ -0,0 +1,168 @@
+use crate::{Float, MathError, Scalar, VecN};
+
+impl<T: Scalar, const N: usize> VecN<T, N> {
+    pub fn magnitude_squared(&self) -> T {
+        self.dot(self)
+    }
+    pub fn distance_squared(&self, other: &Self) -> T {
+        (*self - *other).magnitude_squared()
+    }
+}
+
+// projections and lengths, built on dot and magnitude
+impl<T: Float, const N: usize> VecN<T, N> {
+    pub fn distance(&self, other: &Self) -> T {
+        (*self - *other).magnitude()
+    }
+    pub fn project_onto(&self, other: &Self) -> Self {
+        let length_squared = other.magnitude_squared();
+        // chose not to panic, there is nothing to project onto so the projection is empty
+        if length_squared == T::ZERO {
+            return Self::new();
+        }
+        *other * (self.dot(other) / length_squared)
+    }
+    pub fn reject_from(&self, other: &Self) -> Self {
+        *self - self.project_onto(other)
+    }
+    // normal is expected to be a unit vector, as is self for refract
+    pub fn reflect(&self, normal: &Self) -> Self {
+        *self - *normal * ((T::ONE + T::ONE) * self.dot(normal))
+    }
+    // eta is the ratio of the indices of refraction, total internal reflection gives a zero vector like glsl
+    pub fn refract(&self, normal: &Self, eta: T) -> Self {
+        let cos = self.dot(normal);
+        let k = T::ONE - eta * eta * (T::ONE - cos * cos);
+        if k < T::ZERO {
+            return Self::new();
+        }
+        *self * eta - *normal * (eta * cos + k.sqrt())
+    }
+    pub fn with_length(&self, length: T) -> Self {
+        self.normalise() * length
+    }
+    pub fn clamp_length(&self, min: T, max: T) -> Self {
+        assert!(min <= max, "Minimum length is larger than the maximum");
+        let length = self.magnitude();
+        if length < min {
+            self.with_length(min)
+        } else if length > max {
+            self.with_length(max)
+        } else {
+            *self
+        }
+    }
+}
+
+// angle_between gives the unsigned angle, these are signed in (-pi, pi] and zero for zero vectors
+impl<T: Float> VecN<T, 2> {
+    // counter-clockwise from self to other is positive
+    pub fn signed_angle(&self, other: &Self) -> T {
+        self.perp_dot(other).atan2(self.dot(other))
+    }
+}
+
+impl<T: Float> VecN<T, 3> {
+    // positive when the rotation from self to other is counter-clockwise looking down up
+    pub fn signed_angle(&self, other: &Self, up: &Self) -> T {
+        let cross = self.cross(other);
+        let angle = cross.magnitude().atan2(self.dot(other));
+        if cross.dot(up) < T::ZERO { -angle } else { angle }
+    }
+}
+
+// tangent frames
+impl<T: Float> VecN<T, 3> {
+    // perpendicular to self but not normalised, self must be non-zero
+    pub fn any_orthogonal_vector(&self) -> Self {
+        let [x, y, z] = self.0;
+        // drop the smaller of x and y so the result can't collapse to zero
+        if x.abs() > y.abs() { VecN([-z, T::ZERO, x]) } else { VecN([T::ZERO, z, -y]) }
+    }
+    // self must be a unit vector
+    pub fn any_orthonormal_vector(&self) -> Self {
+        self.any_orthonormal_pair().0
+    }
+    // branchless construction from Duff et al. 2017, "Building an Orthonormal Basis, Revisited".
+    // self must be a unit vector, and (a, b, self) is right handed
+    pub fn any_orthonormal_pair(&self) -> (Self, Self) {
+        let [x, y, z] = self.0;
+        // signum keeps the sign of -0.0 so the z = -1 pole is still handled
+        let sign = z.signum();
+        let a = -T::ONE / (sign + z);
+        let b = x * y * a;
+        (VecN([T::ONE + sign * x * x * a, sign * b, -sign * x]), VecN([b, sign + y * y * a, -y]))
+    }
+    // gram-schmidt, keeps the direction of a and fails if the vectors are linearly dependent
+    pub fn orthonormalize(a: &Self, b: &Self, c: &Self) -> Result<(Self, Self, Self), MathError> {
+        let a = a.try_normalise()?;
+        let b = b.reject_from(&a).try_normalise()?;
+        let c = c.reject_from(&a).reject_from(&b).try_normalise()?;
+        Ok((a, b, c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_vec_approx_eq, MathError, Vec2, Vec3};
+    use std::f32::consts::{FRAC_1_SQRT_2, FRAC_PI_2, FRAC_PI_4};
+
+    #[test]
+    fn geometric_project() {
+        let (a, b) = (Vec3::from_xyz(2.0, 3.0, 0.0), Vec3::from_xyz(4.0, 0.0, 0.0));
+        assert_eq!(a.project_onto(&b), Vec3::from_xyz(2.0, 0.0, 0.0));
+        assert_eq!(a.reject_from(&b), Vec3::from_xyz(0.0, 3.0, 0.0));
+        assert_eq!(a.project_onto(&Vec3::new()), Vec3::new());
+        assert_eq!(a.distance_squared(&b), 13.0);
+        assert_eq!(Vec2::from_xy(3.0, 4.0).distance(&Vec2::new()), 5.0);
+        assert_eq!(Vec2::from_xy(3.0, 4.0).magnitude_squared(), 25.0);
+    }
+    #[test]
+    fn geometric_reflect_refract() {
+        let incident = Vec2::from_xy(1.0, -1.0).normalise();
+        let normal = Vec2::y_axis();
+        assert_vec_approx_eq!(incident.reflect(&normal), Vec2::from_xy(FRAC_1_SQRT_2, FRAC_1_SQRT_2));
+        assert_vec_approx_eq!(incident.refract(&normal, 1.0), incident);
+        // leaving glass at 45 degrees is past the critical angle
+        assert_eq!(incident.refract(&normal, 1.5), Vec2::new());
+        let refracted = incident.refract(&normal, 1.0 / 1.5);
+        assert_vec_approx_eq!(refracted.magnitude(), 1.0);
+        assert_vec_approx_eq!(refracted.x(), FRAC_1_SQRT_2 / 1.5);
+    }
+    #[test]
+    fn geometric_length() {
+        let v = Vec3::from_xyz(0.0, 3.0, 4.0);
+        assert_eq!(v.with_length(10.0), Vec3::from_xyz(0.0, 6.0, 8.0));
+        assert_eq!(v.clamp_length(1.0, 2.5), Vec3::from_xyz(0.0, 1.5, 2.0));
+        assert_eq!(v.clamp_length(10.0, 20.0), Vec3::from_xyz(0.0, 6.0, 8.0));
+        assert_eq!(v.clamp_length(1.0, 20.0), v);
+    }
+    #[test]
+    fn geometric_signed_angle() {
+        assert_vec_approx_eq!(Vec2::x_axis().signed_angle(&Vec2::y_axis()), FRAC_PI_2);
+        assert_vec_approx_eq!(Vec2::y_axis().signed_angle(&Vec2::from_xy(1.0, 1.0)), -FRAC_PI_4);
+        let up = Vec3::z_axis();
+        assert_vec_approx_eq!(Vec3::x_axis().signed_angle(&Vec3::y_axis(), &up), FRAC_PI_2);
+        assert_vec_approx_eq!(Vec3::y_axis().signed_angle(&Vec3::x_axis(), &up), -FRAC_PI_2);
+        assert_eq!(Vec3::new().signed_angle(&Vec3::x_axis(), &up), 0.0);
+    }
+    #[test]
+    fn geometric_orthonormal_pair() {
+        for normal in [Vec3::z_axis(), -Vec3::z_axis(), Vec3::x_axis(), Vec3::from_xyz(1.0, -2.0, 0.5).normalise()] {
+            let (a, b) = normal.any_orthonormal_pair();
+            assert!(a.is_normalized() && b.is_normalized());
+            assert_vec_approx_eq!(a.dot(&b), 0.0, epsilon = 1e-6);
+            assert_vec_approx_eq!(a.dot(&normal), 0.0, epsilon = 1e-6);
+            assert_vec_approx_eq!(a.cross(&b), normal, epsilon = 1e-6);
+            assert_vec_approx_eq!(normal.any_orthogonal_vector().dot(&normal), 0.0, epsilon = 1e-6);
+            assert_eq!(normal.any_orthonormal_vector(), a);
+        }
+    }
+    #[test]
+    fn geometric_orthonormalize() {
+        let (a, b, c) = Vec3::orthonormalize(&Vec3::from_xyz(2.0, 0.0, 0.0), &Vec3::from_xyz(1.0, 1.0, 0.0), &Vec3::from_xyz(1.0, 1.0, 1.0)).unwrap();
+        assert_eq!((a, b, c), (Vec3::x_axis(), Vec3::y_axis(), Vec3::z_axis()));
+        let dependent = Vec3::orthonormalize(&Vec3::x_axis(), &Vec3::y_axis(), &Vec3::from_xyz(1.0, 1.0, 0.0));
+        assert_eq!(dependent, Err(MathError::ZeroLength));
+    }
+}
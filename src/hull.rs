@@ -0,0 +1,296 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use crate::predicates::orient2d;
+use crate::{DVec2, DVec3, Float, VecN};
+
+// convex hulls returning indices into the input slice, non-finite points are ignored
+
+fn lexicographic<T: Float>(a: &VecN<T, 2>, b: &VecN<T, 2>) -> Ordering {
+    a.0[0].partial_cmp(&b.0[0]).unwrap_or(Ordering::Equal)
+        .then(a.0[1].partial_cmp(&b.0[1]).unwrap_or(Ordering::Equal))
+}
+
+// andrew's monotone chain. the hull is counter-clockwise starting from the lowest x (then y)
+// point, and collinear points along an edge and duplicates are left out. all points collinear
+// gives the two ends, a single distinct point gives just that point. turns are decided by the exact
+// orient2d, so nearly collinear points can't make the hull dent inwards or drop a corner
+pub fn convex_hull2<T: Float>(points: &[VecN<T, 2>]) -> Vec<usize> where VecN<T, 2>: Into<DVec2> {
+    let mut order: Vec<usize> = (0..points.len()).filter(|&i| points[i].is_finite()).collect();
+    order.sort_by(|&a, &b| lexicographic(&points[a], &points[b]));
+    order.dedup_by(|a, b| points[*a] == points[*b]);
+    if order.len() < 3 {
+        return order;
+    }
+    // keeps only strict left turns, popping collinear points as well as right turns
+    let converted: Vec<DVec2> = points.iter().map(|&point| point.into()).collect();
+    let turn = |a: usize, b: usize, c: usize| orient2d(converted[a], converted[b], converted[c]);
+    let mut hull: Vec<usize> = Vec::with_capacity(order.len() + 1);
+    for &i in order.iter() {
+        while hull.len() >= 2 && turn(hull[hull.len() - 2], hull[hull.len() - 1], i) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(i);
+    }
+    let lower_len = hull.len() + 1;
+    for &i in order.iter().rev().skip(1) {
+        while hull.len() >= lower_len && turn(hull[hull.len() - 2], hull[hull.len() - 1], i) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(i);
+    }
+    // the last point pushed is the first one again
+    hull.pop();
+    hull
+}
+
+struct Face<T> {
+    vertices: [usize; 3],
+    normal: VecN<T, 3>,
+    offset: T,
+    outside: Vec<usize>,
+    alive: bool,
+}
+
+struct Quickhull<'a, T> {
+    points: &'a [VecN<T, 3>],
+    tolerance: T,
+    faces: Vec<Face<T>>,
+    // directed edge to the face it belongs to, the neighbour across a -> b owns b -> a
+    edges: HashMap<(usize, usize), usize>,
+}
+
+impl<T: Float> Quickhull<'_, T> {
+    fn distance(&self, face: usize, point: usize) -> T {
+        let face = &self.faces[face];
+        face.normal.dot(&self.points[point]) - face.offset
+    }
+    fn add_face(&mut self, vertices: [usize; 3]) -> usize {
+        let [a, b, c] = vertices.map(|i| self.points[i]);
+        let normal = (b - a).cross(&(c - a)).normalise();
+        let index = self.faces.len();
+        self.faces.push(Face { vertices, normal, offset: normal.dot(&a), outside: Vec::new(), alive: true });
+        for edge in 0..3 {
+            self.edges.insert((vertices[edge], vertices[(edge + 1) % 3]), index);
+        }
+        index
+    }
+    // hands each point to the first face it is clearly in front of, the rest are inside
+    fn assign(&mut self, points: impl IntoIterator<Item = usize>, faces: &[usize]) {
+        for point in points {
+            if let Some(&face) = faces.iter().find(|&&face| self.distance(face, point) > self.tolerance) {
+                self.faces[face].outside.push(point);
+            }
+        }
+    }
+    fn expand(&mut self, face: usize) {
+        let eye = *self.faces[face].outside.iter()
+            .max_by(|&&a, &&b| self.distance(face, a).partial_cmp(&self.distance(face, b)).unwrap_or(Ordering::Equal))
+            .expect("only faces with outside points are expanded");
+        // flood out from the face so the visible region stays connected even when the
+        // tolerance makes a far away face look visible
+        let (mut visible, mut stack) = (vec![face], vec![face]);
+        while let Some(current) = stack.pop() {
+            for edge in 0..3 {
+                let [a, b] = [self.faces[current].vertices[edge], self.faces[current].vertices[(edge + 1) % 3]];
+                let neighbour = self.edges[&(b, a)];
+                if !visible.contains(&neighbour) && self.distance(neighbour, eye) > self.tolerance {
+                    visible.push(neighbour);
+                    stack.push(neighbour);
+                }
+            }
+        }
+        let mut horizon = Vec::new();
+        for &current in visible.iter() {
+            for edge in 0..3 {
+                let [a, b] = [self.faces[current].vertices[edge], self.faces[current].vertices[(edge + 1) % 3]];
+                if !visible.contains(&self.edges[&(b, a)]) {
+                    horizon.push((a, b));
+                }
+            }
+        }
+        let mut orphans = Vec::new();
+        for &current in visible.iter() {
+            let face = &mut self.faces[current];
+            face.alive = false;
+            orphans.append(&mut face.outside);
+            let vertices = face.vertices;
+            for edge in 0..3 {
+                self.edges.remove(&(vertices[edge], vertices[(edge + 1) % 3]));
+            }
+        }
+        // the horizon keeps the winding of the visible faces, so the new faces face outwards too
+        let new_faces: Vec<usize> = horizon.into_iter().map(|(a, b)| self.add_face([a, b, eye])).collect();
+        self.assign(orphans.into_iter().filter(|&point| point != eye), &new_faces);
+    }
+}
+
+// quickhull. faces are counter-clockwise seen from outside and index into points. points within a
+// tolerance scaled to the input's extent of a face are treated as on it, so coplanar and collinear
+// points on the surface are not hull vertices. coplanar input gives a flat hull with every
+// triangle in both windings, collinear input or fewer than three distinct points gives no faces
+pub fn convex_hull3<T: Float>(points: &[VecN<T, 3>]) -> Vec<[usize; 3]> where VecN<T, 3>: Into<DVec3> {
+    let finite: Vec<usize> = (0..points.len()).filter(|&i| points[i].is_finite()).collect();
+    if finite.is_empty() {
+        return Vec::new();
+    }
+    let extent = finite.iter().fold(VecN::<T, 3>::new(), |extent, &i| extent.max(&points[i].abs()));
+    let three = T::ONE + T::ONE + T::ONE;
+    let tolerance = three * extent.sum() * T::EPSILON;
+
+    // the initial tetrahedron from the extreme points, checking for each degenerate case on the way
+    let mut extremes = Vec::new();
+    for axis in 0..3 {
+        let by_axis = |&a: &usize, &b: &usize| points[a].0[axis].partial_cmp(&points[b].0[axis]).unwrap_or(Ordering::Equal);
+        extremes.extend(finite.iter().copied().min_by(by_axis));
+        extremes.extend(finite.iter().copied().max_by(by_axis));
+    }
+    let farthest = |candidates: &[usize], measure: &dyn Fn(usize) -> T| -> usize {
+        *candidates.iter().max_by(|&&a, &&b| measure(a).partial_cmp(&measure(b)).unwrap_or(Ordering::Equal)).unwrap()
+    };
+    let first = extremes[0];
+    let first = farthest(&extremes, &|i| points[i].distance(&points[first]));
+    let second = farthest(&extremes, &|i| points[i].distance(&points[first]));
+    let axis = points[second] - points[first];
+    if axis.magnitude() <= tolerance {
+        return Vec::new();
+    }
+    let line_distance = |i: usize| (points[i] - points[first]).cross(&axis).magnitude() / axis.magnitude();
+    let third = farthest(&finite, &line_distance);
+    if line_distance(third) <= tolerance {
+        return Vec::new();
+    }
+    let normal = axis.cross(&(points[third] - points[first])).normalise();
+    let plane_distance = |i: usize| normal.dot(&(points[i] - points[first]));
+    let fourth = farthest(&finite, &|i| plane_distance(i).abs());
+    if plane_distance(fourth).abs() <= tolerance {
+        return planar_hull(points, &finite, normal);
+    }
+
+    let mut hull = Quickhull { points, tolerance, faces: Vec::new(), edges: HashMap::new() };
+    // wind the base away from the fourth point
+    let base = if plane_distance(fourth) > T::ZERO { [first, third, second] } else { [first, second, third] };
+    let [a, b, c] = base;
+    let faces = [
+        hull.add_face([a, b, c]),
+        hull.add_face([a, fourth, b]),
+        hull.add_face([b, fourth, c]),
+        hull.add_face([c, fourth, a]),
+    ];
+    hull.assign(finite.into_iter().filter(|point| !base.contains(point) && *point != fourth), &faces);
+    while let Some(face) = (0..hull.faces.len()).find(|&face| hull.faces[face].alive && !hull.faces[face].outside.is_empty()) {
+        hull.expand(face);
+    }
+    hull.faces.into_iter().filter(|face| face.alive).map(|face| face.vertices).collect()
+}
+
+// the 2d hull in the plane, fanned into triangles on both sides
+fn planar_hull<T: Float>(points: &[VecN<T, 3>], finite: &[usize], normal: VecN<T, 3>) -> Vec<[usize; 3]> where VecN<T, 3>: Into<DVec3> {
+    let (u, v) = normal.any_orthonormal_pair();
+    let (u, v): (DVec3, DVec3) = (u.into(), v.into());
+    let projected: Vec<DVec2> = finite.iter().map(|&i| {
+        let point: DVec3 = points[i].into();
+        VecN([point.dot(&u), point.dot(&v)])
+    }).collect();
+    let outline: Vec<usize> = convex_hull2(&projected).into_iter().map(|i| finite[i]).collect();
+    // (u, v, normal) is right handed, so counter-clockwise in the plane faces along the normal
+    (1..outline.len().saturating_sub(1)).flat_map(|i| {
+        [[outline[0], outline[i], outline[i + 1]], [outline[0], outline[i + 1], outline[i]]]
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Vec2, Vec3};
+
+    fn volume(points: &[Vec3], faces: &[[usize; 3]]) -> f32 {
+        faces.iter().map(|&[a, b, c]| points[a].dot(&points[b].cross(&points[c]))).sum::<f32>() / 6.0
+    }
+
+    #[test]
+    fn hull2_square_with_extras() {
+        let points = [
+            Vec2::splat(1.0), Vec2::new(), Vec2::from_xy(2.0, 0.0), Vec2::from_xy(1.0, 0.0),
+            Vec2::splat(2.0), Vec2::from_xy(0.0, 2.0), Vec2::from_xy(0.0, 1.0), Vec2::splat(2.0),
+        ];
+        // interior, collinear and duplicate points are all dropped
+        assert_eq!(convex_hull2(&points), vec![1, 2, 4, 5]);
+    }
+    #[test]
+    fn hull2_degenerate() {
+        assert_eq!(convex_hull2::<f32>(&[]), Vec::<usize>::new());
+        assert_eq!(convex_hull2(&[Vec2::splat(1.0), Vec2::splat(1.0)]), vec![0]);
+        let line: Vec<Vec2> = (0..5).map(|i| Vec2::splat(i as f32)).collect();
+        assert_eq!(convex_hull2(&line), vec![0, 4]);
+        assert_eq!(convex_hull2(&[Vec2::new(), Vec2::splat(f32::NAN), Vec2::x_axis(), Vec2::y_axis()]), vec![0, 2, 3]);
+    }
+    #[test]
+    fn hull2_nearly_collinear() {
+        // a grid of neighbouring floats near a long diagonal, where rounded cross products get the turn wrong
+        let mut points = vec![Vec2::from_xy(12.0, 12.0), Vec2::from_xy(24.0, 24.0)];
+        for i in 0..16 {
+            for j in 0..16 {
+                points.push(Vec2::from_xy(0.5 + i as f32 * f32::EPSILON / 2.0, 0.5 + j as f32 * f32::EPSILON / 2.0));
+            }
+        }
+        let hull = convex_hull2(&points);
+        // strictly convex, and no input point outside any edge
+        for i in 0..hull.len() {
+            let (a, b, c) = (points[hull[i]], points[hull[(i + 1) % hull.len()]], points[hull[(i + 2) % hull.len()]]);
+            assert!(orient2d(a, b, c) > 0.0);
+            assert!(points.iter().all(|&p| orient2d(a, b, p) >= 0.0));
+        }
+    }
+    #[test]
+    fn hull3_cube() {
+        let mut points: Vec<Vec3> = (0..8).map(|i| Vec3::from_xyz((i & 1) as f32, ((i >> 1) & 1) as f32, ((i >> 2) & 1) as f32)).collect();
+        // interior, face centre and edge midpoint
+        points.extend([Vec3::splat(0.5), Vec3::from_xyz(0.5, 0.5, 1.0), Vec3::from_xyz(1.0, 0.5, 0.0)]);
+        let faces = convex_hull3(&points);
+        assert_eq!(faces.len(), 12);
+        assert!(faces.iter().flatten().all(|&i| i < 8));
+        assert!((volume(&points, &faces) - 1.0).abs() < 1e-6);
+        // outward facing, every other point is behind every face
+        for &[a, b, c] in faces.iter() {
+            let normal = (points[b] - points[a]).cross(&(points[c] - points[a]));
+            assert!(points.iter().all(|p| normal.dot(&(*p - points[a])) <= 1e-6));
+        }
+    }
+    #[test]
+    fn hull3_sphere_points() {
+        // a fibonacci sphere, every point is a hull vertex
+        let points: Vec<Vec3> = (0..200).map(|i| {
+            let y = 1.0 - 2.0 * (i as f32 + 0.5) / 200.0;
+            let angle = i as f32 * 2.399963;
+            let radius = (1.0 - y * y).sqrt();
+            Vec3::from_xyz(radius * angle.cos(), y, radius * angle.sin())
+        }).collect();
+        let faces = convex_hull3(&points);
+        // a closed triangulated sphere has 2v - 4 faces
+        assert_eq!(faces.len(), 2 * points.len() - 4);
+        let volume = volume(&points, &faces);
+        assert!(volume > 4.0 && volume < 4.0 * std::f32::consts::PI / 3.0);
+    }
+    #[test]
+    fn hull3_degenerate() {
+        assert!(convex_hull3::<f32>(&[]).is_empty());
+        let line: Vec<Vec3> = (0..4).map(|i| Vec3::splat(i as f32)).collect();
+        assert!(convex_hull3(&line).is_empty());
+        let plane = [Vec3::new(), Vec3::x_axis(), Vec3::from_xyz(1.0, 1.0, 0.0), Vec3::y_axis(), Vec3::from_xyz(0.5, 0.5, 0.0)];
+        let faces = convex_hull3(&plane);
+        assert_eq!(faces.len(), 4);
+        assert!(faces.iter().flatten().all(|&i| i != 4));
+        assert_eq!(volume(&plane, &faces), 0.0);
+    }
+    #[test]
+    fn hull3_lattice() {
+        // every face is full of coplanar points and every edge of collinear ones
+        let points: Vec<Vec3> = (0..125).map(|i| Vec3::from_xyz((i % 5) as f32, ((i / 5) % 5) as f32, (i / 25) as f32)).collect();
+        let faces = convex_hull3(&points);
+        assert!((volume(&points, &faces) - 64.0).abs() < 1e-3);
+        let mut vertices: Vec<usize> = faces.iter().flatten().copied().collect();
+        vertices.sort();
+        vertices.dedup();
+        assert_eq!(vertices, vec![0, 4, 20, 24, 100, 104, 120, 124]);
+    }
+}
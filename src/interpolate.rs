@@ -0,0 +1,145 @@
+use std::cmp::Ordering;
+use crate::{Float, VecN};
+use crate::scalar::clamp_unit;
+
+// interpolation between vectors, t is not clamped unless the name says it eases
+impl<T: Float, const N: usize> VecN<T, N> {
+    pub fn lerp(&self, end: &Self, t: T) -> Self {
+        *self + (*end - *self) * t
+    }
+    pub fn nlerp(&self, end: &Self, t: T) -> Self {
+        self.lerp(end, t).normalise()
+    }
+    // both ends are expected to be unit vectors
+    pub fn slerp(&self, end: &Self, t: T) -> Self {
+        let dot = self.dot(end);
+        let tolerance = T::ONE - T::EPSILON.sqrt();
+        // nearly parallel, sin(angle) would be ~0 so fall back to nlerp
+        if dot > tolerance {
+            return self.nlerp(end, t);
+        }
+        // the great circle runs through the part of end orthogonal to self, rejected a second time so
+        // rounding in the first pass can't tilt it out of the plane. atan2 keeps the angle accurate
+        // near pi where acos is not
+        let orthogonal = *end - *self * dot;
+        let length = orthogonal.magnitude();
+        let (direction, angle) = if length <= T::EPSILON {
+            // exactly opposite, every great circle joins them so pick one through an arbitrary orthogonal vector
+            (self.any_orthogonal_axis(), T::PI)
+        } else {
+            (orthogonal.reject_from(self).normalise(), length.atan2(dot))
+        };
+        *self * (angle * t).cos() + direction * (angle * t).sin()
+    }
+    // any_orthogonal_vector is 3d only, so reject the axis self is least aligned with instead
+    fn any_orthogonal_axis(&self) -> Self {
+        let smallest = (0..N).min_by(|&i, &j| self.0[i].abs().partial_cmp(&self.0[j].abs()).unwrap_or(Ordering::Equal)).unwrap_or(0);
+        Self::axis(smallest).reject_from(self).normalise()
+    }
+    pub fn smoothstep(&self, end: &Self, t: T) -> Self {
+        let t = clamp_unit(t);
+        let (two, three) = (T::ONE + T::ONE, T::ONE + T::ONE + T::ONE);
+        self.lerp(end, t * t * (three - two * t))
+    }
+    pub fn smootherstep(&self, end: &Self, t: T) -> Self {
+        let t = clamp_unit(t);
+        let (two, three) = (T::ONE + T::ONE, T::ONE + T::ONE + T::ONE);
+        let (six, ten, fifteen) = (two * three, two * (two + three), three * (two + three));
+        self.lerp(end, t * t * t * (t * (t * six - fifteen) + ten))
+    }
+    // moves a straight line towards target without overshooting it
+    pub fn move_towards(&self, target: &Self, max_delta: T) -> Self {
+        let delta = *target - *self;
+        let distance = delta.magnitude();
+        if distance <= max_delta || distance == T::ZERO {
+            return *target;
+        }
+        *self + delta * (max_delta / distance)
+    }
+    // critically damped spring towards target which reaches it in roughly smooth_time,
+    // velocity is carried between calls by the caller
+    pub fn smooth_damp(&self, target: &Self, velocity: &mut Self, smooth_time: T, delta_time: T) -> Self {
+        if smooth_time <= T::ZERO {
+            *velocity = Self::new();
+            return *target;
+        }
+        let omega = (T::ONE + T::ONE) / smooth_time;
+        let decay = (-omega * delta_time).exp();
+        let change = *self - *target;
+        let temp = (*velocity + change * omega) * delta_time;
+        *velocity = (*velocity - temp * omega) * decay;
+        let output = *target + (change + temp) * decay;
+        // a critically damped spring never crosses the target, but rounding can put it just past
+        if (*target - *self).dot(&(output - *target)) > T::ZERO {
+            *velocity = Self::new();
+            return *target;
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_vec_approx_eq, Vec2, Vec3};
+    use std::f32::consts::FRAC_1_SQRT_2;
+
+    #[test]
+    fn interpolate_lerp() {
+        let (a, b) = (Vec3::from_xyz(0.0, 2.0, 4.0), Vec3::from_xyz(4.0, 2.0, 0.0));
+        assert_eq!(a.lerp(&b, 0.25), Vec3::from_xyz(1.0, 2.0, 3.0));
+        assert_eq!(a.smoothstep(&b, 0.5), Vec3::from_xyz(2.0, 2.0, 2.0));
+        assert_eq!(a.smoothstep(&b, 2.0), b);
+        assert_eq!(a.smootherstep(&b, -1.0), a);
+        assert_vec_approx_eq!(a.smootherstep(&b, 0.5), Vec3::from_xyz(2.0, 2.0, 2.0));
+        assert_vec_approx_eq!(Vec2::x_axis().nlerp(&Vec2::y_axis(), 0.5), Vec2::from_xy(1.0, 1.0).normalise());
+    }
+    #[test]
+    fn interpolate_slerp() {
+        let (a, b) = (Vec3::x_axis(), Vec3::y_axis());
+        let half = std::f32::consts::FRAC_1_SQRT_2;
+        assert_vec_approx_eq!(a.slerp(&b, 0.5), Vec3::from_xyz(half, half, 0.0));
+        assert_vec_approx_eq!(a.slerp(&b, 1.0 / 3.0), Vec3::from_xyz(0.8660254, 0.5, 0.0));
+        assert_vec_approx_eq!(a.slerp(&a, 0.3), a);
+    }
+    #[test]
+    fn interpolate_slerp_opposite() {
+        let (a, b) = (Vec3::x_axis(), -Vec3::x_axis());
+        let midpoint = a.slerp(&b, 0.5);
+        assert_vec_approx_eq!(midpoint.magnitude(), 1.0);
+        assert_vec_approx_eq!(midpoint.dot(&a), 0.0, epsilon = 1e-6);
+        assert_vec_approx_eq!(a.slerp(&b, 1.0), b, epsilon = 1e-6);
+        assert_vec_approx_eq!(a.slerp(&b, 0.0), a);
+        let quarter = Vec2::y_axis().slerp(&-Vec2::y_axis(), 0.25);
+        assert_vec_approx_eq!(quarter, Vec2::from_xy(FRAC_1_SQRT_2, FRAC_1_SQRT_2));
+        // nearly opposite is not opposite, the path still has to end at end
+        for degrees in [170.0f32, 178.6, 179.0, 179.9, 179.99] {
+            let angle = degrees.to_radians();
+            let end = Vec3::from_xyz(angle.cos(), angle.sin(), 0.0);
+            assert_vec_approx_eq!(a.slerp(&end, 1.0), end, epsilon = 1e-6);
+            let midpoint = a.slerp(&end, 0.5);
+            assert_vec_approx_eq!(midpoint, Vec3::from_xyz((angle / 2.0).cos(), (angle / 2.0).sin(), 0.0), epsilon = 1e-6);
+            let end = Vec3::from_xyz(-1.0, 1e-3 * degrees, -2e-3).normalise();
+            assert_vec_approx_eq!(a.slerp(&end, 1.0), end, epsilon = 1e-6);
+            assert_vec_approx_eq!(a.slerp(&end, 0.3).magnitude(), 1.0, epsilon = 1e-6);
+        }
+    }
+    #[test]
+    fn interpolate_move_towards() {
+        let (a, b) = (Vec2::new(), Vec2::from_xy(3.0, 4.0));
+        assert_eq!(a.move_towards(&b, 1.0), Vec2::from_xy(0.6, 0.8));
+        assert_eq!(a.move_towards(&b, 10.0), b);
+        assert_eq!(b.move_towards(&b, 1.0), b);
+    }
+    #[test]
+    fn interpolate_smooth_damp() {
+        let (mut position, target, mut velocity) = (Vec2::new(), Vec2::from_xy(10.0, 0.0), Vec2::new());
+        let mut previous = position;
+        for _ in 0..120 {
+            position = position.smooth_damp(&target, &mut velocity, 0.3, 1.0 / 60.0);
+            // approaches monotonically, never overshoots
+            assert!(position.x() >= previous.x() && position.x() <= target.x());
+            previous = position;
+        }
+        assert_vec_approx_eq!(position, target, epsilon = 1e-2);
+    }
+}
@@ -0,0 +1,301 @@
+use std::cmp::Ordering;
+use std::ops::{Mul,Add,Sub,Neg,AddAssign,SubAssign,MulAssign};
+use crate::{Scalar, Signed, Float, VecN};
+
+// column-major, self.0[col][row]
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MatN<T, const N: usize>(pub(crate) [[T; N]; N]);
+
+pub type Mat2 = MatN<f32, 2>;
+pub type Mat3 = MatN<f32, 3>;
+pub type Mat4 = MatN<f32, 4>;
+pub type DMat2 = MatN<f64, 2>;
+pub type DMat3 = MatN<f64, 3>;
+pub type DMat4 = MatN<f64, 4>;
+
+impl<T: Scalar, const N: usize> MatN<T, N> {
+    pub fn zero() -> Self {
+        MatN([[T::ZERO; N]; N])
+    }
+    pub fn identity() -> Self {
+        MatN(std::array::from_fn(|col| VecN::<T, N>::axis(col).0))
+    }
+    pub fn from_cols(cols: [VecN<T, N>; N]) -> Self {
+        MatN(cols.map(|col| col.0))
+    }
+    pub fn from_rows(rows: [VecN<T, N>; N]) -> Self {
+        Self::from_cols(rows).transpose()
+    }
+    pub fn from_cols_array(cols: [[T; N]; N]) -> Self {
+        MatN(cols)
+    }
+    pub fn to_cols_array(&self) -> [[T; N]; N] {
+        self.0
+    }
+    // the columns one after the other
+    pub fn as_slice(&self) -> &[T] {
+        self.0.as_flattened()
+    }
+    pub fn from_diagonal(diagonal: &VecN<T, N>) -> Self {
+        let mut mat = Self::zero();
+        (0..N).for_each(|i| mat.0[i][i] = diagonal.0[i]);
+        mat
+    }
+    pub fn col(&self, index: usize) -> VecN<T, N> {
+        VecN(self.0[index])
+    }
+    pub fn row(&self, index: usize) -> VecN<T, N> {
+        VecN(self.0.map(|col| col[index]))
+    }
+    pub fn set_col(&mut self, index: usize, col: &VecN<T, N>) {
+        self.0[index] = col.0;
+    }
+    pub fn set_row(&mut self, index: usize, row: &VecN<T, N>) {
+        (0..N).for_each(|col| self.0[col][index] = row.0[col]);
+    }
+    pub fn transpose(&self) -> Self {
+        MatN(std::array::from_fn(|col| self.row(col).0))
+    }
+    pub fn trace(&self) -> T {
+        (0..N).map(|i| self.0[i][i]).sum()
+    }
+    fn map(&self, f: impl Fn(T) -> T) -> Self {
+        MatN(self.0.map(|col| col.map(&f)))
+    }
+    fn zip_map(&self, other: &Self, f: impl Fn(T, T) -> T) -> Self {
+        MatN(std::array::from_fn(|col| std::array::from_fn(|row| f(self.0[col][row], other.0[col][row]))))
+    }
+}
+
+impl<T: Signed> MatN<T, 2> {
+    pub fn determinant(&self) -> T {
+        let [[a, b], [c, d]] = self.0;
+        a * d - c * b
+    }
+}
+
+impl<T: Signed> MatN<T, 3> {
+    pub fn determinant(&self) -> T {
+        // scalar triple product of the columns
+        self.col(0).dot(&self.col(1).cross(&self.col(2)))
+    }
+}
+
+impl<T: Signed> MatN<T, 4> {
+    pub fn determinant(&self) -> T {
+        // laplace expansion down the first column
+        let mut det = T::ZERO;
+        let mut sign = T::ONE;
+        for row in 0..4 {
+            let minor: [[T; 3]; 3] = std::array::from_fn(|col| {
+                let full = self.0[col + 1];
+                std::array::from_fn(|i| full[if i < row { i } else { i + 1 }])
+            });
+            det += sign * self.0[0][row] * MatN(minor).determinant();
+            sign = -sign;
+        }
+        det
+    }
+}
+
+impl<T: Float, const N: usize> MatN<T, N> {
+    pub fn inverse(&self) -> Option<Self> {
+        // gauss-jordan elimination with partial pivoting, works on rows so transpose in and out
+        let mut a = self.transpose().0;
+        let mut inv = Self::identity().0;
+        // rounding leaves a singular matrix with tiny rather than zero pivots. each entry carries the
+        // sum of the magnitudes that went into it, and a pivot within rounding error of that is the
+        // result of cancellation. entries that were never eliminated keep their own magnitude, so
+        // rows and columns of very different scale don't look singular
+        let mut magnitude = a.map(|row| row.map(|val| val.abs()));
+        let tolerance = (0..N).fold(T::ZERO, |size, _| size + T::ONE) * T::EPSILON;
+        for col in 0..N {
+            let pivot = (col..N).max_by(|&i, &j| a[i][col].abs().partial_cmp(&a[j][col].abs()).unwrap_or(Ordering::Equal))?;
+            // chose to return None rather than panic, a singular matrix is a normal input
+            if a[pivot][col].abs() <= tolerance * magnitude[pivot][col] || !a[pivot][col].is_finite() {
+                return None;
+            }
+            a.swap(col, pivot);
+            inv.swap(col, pivot);
+            magnitude.swap(col, pivot);
+            let scale = T::ONE / a[col][col];
+            a[col] = a[col].map(|val| val * scale);
+            inv[col] = inv[col].map(|val| val * scale);
+            magnitude[col] = magnitude[col].map(|val| val * scale.abs());
+            for row in 0..N {
+                if row == col {
+                    continue;
+                }
+                let factor = a[row][col];
+                let (a_col, inv_col, magnitude_col) = (a[col], inv[col], magnitude[col]);
+                a[row].iter_mut().zip(a_col).for_each(|(val, pivot_val)| *val -= factor * pivot_val);
+                inv[row].iter_mut().zip(inv_col).for_each(|(val, pivot_val)| *val -= factor * pivot_val);
+                magnitude[row].iter_mut().zip(magnitude_col).for_each(|(val, pivot_val)| *val += factor.abs() * pivot_val);
+            }
+        }
+        Some(MatN(inv).transpose())
+    }
+}
+
+impl<T: Scalar, const N: usize> Mul<MatN<T, N>> for MatN<T, N> {
+    type Output = MatN<T, N>;
+    fn mul(self, other: MatN<T, N>) -> Self::Output {
+        MatN(other.0.map(|col| (self * VecN(col)).0))
+    }
+}
+impl<T: Scalar, const N: usize> Mul<&MatN<T, N>> for &MatN<T, N> {
+    type Output = MatN<T, N>;
+    fn mul(self, other: &MatN<T, N>) -> Self::Output {
+        *self * *other
+    }
+}
+impl<T: Scalar, const N: usize> Mul<MatN<T, N>> for &MatN<T, N> {
+    type Output = MatN<T, N>;
+    fn mul(self, other: MatN<T, N>) -> Self::Output {
+        *self * other
+    }
+}
+impl<T: Scalar, const N: usize> Mul<&MatN<T, N>> for MatN<T, N> {
+    type Output = MatN<T, N>;
+    fn mul(self, other: &MatN<T, N>) -> Self::Output {
+        self * *other
+    }
+}
+impl<T: Scalar, const N: usize> MulAssign<&MatN<T, N>> for MatN<T, N> {
+    fn mul_assign(&mut self, other: &MatN<T, N>) {
+        *self = *self * *other;
+    }
+}
+impl<T: Scalar, const N: usize> MulAssign<MatN<T, N>> for MatN<T, N> {
+    fn mul_assign(&mut self, other: MatN<T, N>) {
+        *self = *self * other;
+    }
+}
+impl<T: Scalar, const N: usize> Mul<VecN<T, N>> for MatN<T, N> {
+    type Output = VecN<T, N>;
+    fn mul(self, vector: VecN<T, N>) -> Self::Output {
+        // linear combination of the columns
+        VecN(std::array::from_fn(|row| (0..N).map(|col| self.0[col][row] * vector.0[col]).sum()))
+    }
+}
+impl<T: Scalar, const N: usize> Mul<&VecN<T, N>> for &MatN<T, N> {
+    type Output = VecN<T, N>;
+    fn mul(self, vector: &VecN<T, N>) -> Self::Output {
+        *self * *vector
+    }
+}
+impl<T: Scalar, const N: usize> Mul<VecN<T, N>> for &MatN<T, N> {
+    type Output = VecN<T, N>;
+    fn mul(self, vector: VecN<T, N>) -> Self::Output {
+        *self * vector
+    }
+}
+impl<T: Scalar, const N: usize> Mul<&VecN<T, N>> for MatN<T, N> {
+    type Output = VecN<T, N>;
+    fn mul(self, vector: &VecN<T, N>) -> Self::Output {
+        self * *vector
+    }
+}
+impl<T: Scalar, const N: usize> Mul<T> for MatN<T, N> {
+    type Output = MatN<T, N>;
+    fn mul(self, scale: T) -> Self::Output {
+        self.map(|val| val * scale)
+    }
+}
+impl<T: Signed, const N: usize> Neg for MatN<T, N> {
+    type Output = MatN<T, N>;
+    fn neg(self) -> Self::Output {
+        self.map(|val| -val)
+    }
+}
+
+macro_rules! matrix_op {
+    ($Op:ident, $op_fn:ident, $OpAssign:ident, $op_assign_fn:ident, $op:tt) => {
+        impl<T: Scalar, const N: usize> $Op<MatN<T, N>> for MatN<T, N> {
+            type Output = MatN<T, N>;
+            fn $op_fn(self, other: MatN<T, N>) -> Self::Output {
+                self.zip_map(&other, |a, b| a $op b)
+            }
+        }
+        impl<T: Scalar, const N: usize> $Op<&MatN<T, N>> for &MatN<T, N> {
+            type Output = MatN<T, N>;
+            fn $op_fn(self, other: &MatN<T, N>) -> Self::Output {
+                *self $op *other
+            }
+        }
+        impl<T: Scalar, const N: usize> $OpAssign<MatN<T, N>> for MatN<T, N> {
+            fn $op_assign_fn(&mut self, other: MatN<T, N>) {
+                *self = *self $op other;
+            }
+        }
+    };
+}
+
+matrix_op!(Add, add, AddAssign, add_assign, +);
+matrix_op!(Sub, sub, SubAssign, sub_assign, -);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Vec2, Vec3, Vec4};
+
+    #[test]
+    fn matrix_identity() {
+        let m = Mat3::from_cols_array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        assert_eq!(m * Mat3::identity(), m);
+        assert_eq!(Mat3::identity() * Vec3::from_xyz(1.0, 2.0, 3.0), Vec3::from_xyz(1.0, 2.0, 3.0));
+        assert_eq!(Mat2::zero() * Vec2::from_xy(1.0, 2.0), Vec2::new());
+    }
+    #[test]
+    fn matrix_rows_cols() {
+        let m = Mat2::from_cols([Vec2::from_xy(1.0, 2.0), Vec2::from_xy(3.0, 4.0)]);
+        assert_eq!(m.col(1), Vec2::from_xy(3.0, 4.0));
+        assert_eq!(m.row(1), Vec2::from_xy(2.0, 4.0));
+        assert_eq!(m.transpose(), Mat2::from_rows([Vec2::from_xy(1.0, 2.0), Vec2::from_xy(3.0, 4.0)]));
+        assert_eq!(m.trace(), 5.0);
+    }
+    #[test]
+    fn matrix_mul() {
+        let a = Mat2::from_rows([Vec2::from_xy(1.0, 2.0), Vec2::from_xy(3.0, 4.0)]);
+        let b = Mat2::from_rows([Vec2::from_xy(5.0, 6.0), Vec2::from_xy(7.0, 8.0)]);
+        assert_eq!(a * b, Mat2::from_rows([Vec2::from_xy(19.0, 22.0), Vec2::from_xy(43.0, 50.0)]));
+        let m = Mat2::from_rows([Vec2::from_xy(1.0, 2.0), Vec2::from_xy(3.0, 4.0)]);
+        assert_eq!(m * Vec2::from_xy(1.0, 1.0), Vec2::from_xy(3.0, 7.0));
+    }
+    #[test]
+    fn matrix_determinant() {
+        assert_eq!(Mat2::from_cols_array([[1.0, 2.0], [3.0, 4.0]]).determinant(), -2.0);
+        assert_eq!(Mat3::from_cols_array([[2.0, 0.0, 0.0], [1.0, 3.0, 0.0], [5.0, 1.0, 4.0]]).determinant(), 24.0);
+        let m = MatN::<i32, 4>::from_cols_array([[1, 0, 2, -1], [3, 0, 0, 5], [2, 1, 4, -3], [1, 0, 5, 0]]);
+        assert_eq!(m.determinant(), 30);
+        assert_eq!(m.transpose().determinant(), 30);
+    }
+    #[test]
+    fn matrix_inverse() {
+        let m = Mat4::from_cols_array([[2.0, 0.0, 0.0, 0.0], [0.0, 4.0, 0.0, 0.0], [0.0, 0.0, 0.5, 0.0], [1.0, 2.0, 3.0, 1.0]]);
+        let inv = m.inverse().unwrap();
+        assert_eq!(inv * m, Mat4::identity());
+        assert_eq!(inv * Vec4::from_xyzw(3.0, 6.0, 3.5, 1.0), Vec4::from_xyzw(1.0, 1.0, 1.0, 1.0));
+        let m = DMat3::from_cols_array([[1.0, 0.0, 0.0], [2.0, 1.0, 0.0], [0.0, 3.0, 1.0]]);
+        assert_eq!(m.inverse().unwrap(), DMat3::from_cols_array([[1.0, 0.0, 0.0], [-2.0, 1.0, 0.0], [6.0, -3.0, 1.0]]));
+        assert_eq!(Mat3::from_cols_array([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 0.0]]).inverse(), None);
+    }
+    #[test]
+    fn matrix_inverse_near_singular() {
+        // singular, but the rounded elimination leaves a last pivot of ~1e-8 instead of zero
+        assert_eq!(Mat3::from_cols_array([[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9]]).inverse(), None);
+        assert_eq!(DMat3::from_cols_array([[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9]]).inverse(), None);
+        // small entries are not singular on their own, the tolerance scales with the matrix
+        let tiny = Mat2::from_cols_array([[1e-30, 0.0], [0.0, 2e-30]]);
+        assert_eq!(tiny.inverse().unwrap() * tiny, Mat2::identity());
+        // well conditioned matrices mixing scales, nothing cancels so none of these are near singular
+        let translation = Mat4::from_translation(&Vec3::from_xyz(3e6, 0.0, 0.0));
+        assert_eq!(translation.inverse(), Some(Mat4::from_translation(&Vec3::from_xyz(-3e6, 0.0, 0.0))));
+        let translation = Mat4::from_translation(&Vec3::from_xyz(1e9, -2e8, 5e7));
+        assert_eq!(translation.inverse().unwrap() * translation, Mat4::identity());
+        assert_eq!(Mat2::from_cols_array([[1e7, 0.0], [0.0, 1.0]]).inverse(), Some(Mat2::from_cols_array([[1e-7, 0.0], [0.0, 1.0]])));
+        assert_eq!(DMat2::from_cols_array([[1e16, 0.0], [0.0, 1.0]]).inverse(), Some(DMat2::from_cols_array([[1e-16, 0.0], [0.0, 1.0]])));
+        assert_eq!(Mat2::from_cols_array([[f32::INFINITY, 0.0], [0.0, 1.0]]).inverse(), None);
+    }
+}
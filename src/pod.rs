@@ -0,0 +1,38 @@
+use bytemuck::{Pod, Zeroable};
+use crate::{MatN, Quaternion, VecN, Vec3A, Vec4A, Mat3A, Vec3xN};
+
+// safety: all of these are repr(C) wrappers around arrays of T, so they have no padding
+// and every bit pattern is valid whenever it is for T
+unsafe impl<T: Zeroable, const N: usize> Zeroable for VecN<T, N> {}
+unsafe impl<T: Pod, const N: usize> Pod for VecN<T, N> {}
+unsafe impl<T: Zeroable, const N: usize> Zeroable for MatN<T, N> {}
+unsafe impl<T: Pod, const N: usize> Pod for MatN<T, N> {}
+unsafe impl<T: Zeroable> Zeroable for Quaternion<T> {}
+unsafe impl<T: Pod> Pod for Quaternion<T> {}
+unsafe impl<T: Zeroable, const L: usize> Zeroable for Vec3xN<T, L> {}
+unsafe impl<T: Pod, const L: usize> Pod for Vec3xN<T, L> {}
+// safety: the padding lane is an explicit field, so the 16 byte alignment adds no implicit padding
+unsafe impl Zeroable for Vec3A {}
+unsafe impl Pod for Vec3A {}
+unsafe impl Zeroable for Vec4A {}
+unsafe impl Pod for Vec4A {}
+unsafe impl Zeroable for Mat3A {}
+unsafe impl Pod for Mat3A {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Mat3, Mat3A, Mat4, Quat, Vec3, Vec3A};
+
+    #[test]
+    fn pod_cast() {
+        let points = [Vec3::from_xyz(1.0, 2.0, 3.0), Vec3::from_xyz(4.0, 5.0, 6.0)];
+        assert_eq!(bytemuck::cast_slice::<Vec3, f32>(&points), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(bytemuck::cast_slice::<Vec3, u8>(&points).len(), 24);
+        assert_eq!(bytemuck::cast::<Mat4, [f32; 16]>(Mat4::identity())[15], 1.0);
+        assert_eq!(bytemuck::cast::<Quat, [f32; 4]>(Quat::identity()), [0.0, 0.0, 0.0, 1.0]);
+        let aligned = [Vec3A::from_xyz(1.0, 2.0, 3.0)];
+        assert_eq!(bytemuck::cast_slice::<Vec3A, f32>(&aligned), &[1.0, 2.0, 3.0, 0.0]);
+        assert_eq!(bytemuck::bytes_of(&Mat3A::from(Mat3::identity())).len(), 48);
+        assert_eq!(<Vec3 as bytemuck::Zeroable>::zeroed(), Vec3::new());
+    }
+}
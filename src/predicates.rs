@@ -0,0 +1,346 @@
+use crate::{DVec2, DVec3, VecN};
+
+// shewchuk's robust geometric predicates, "adaptive precision floating-point arithmetic and fast
+// robust geometric predicates" (1997). each one is the sign of a determinant, evaluated in stages
+// that stop as soon as an error bound can vouch for the sign:
+//  a. a plain f64 evaluation against a forward error bound
+//  b. the determinant of the rounded coordinate differences computed exactly, which only misses the
+//     rounding error of those differences. when they were exact this is the exact result
+//  c. orient2d only, b corrected by the first order terms of the difference tails
+//  d. the exact determinant of the exact differences
+// the paper also has a stage c for orient3d, incircle and insphere, here they go straight from b to d.
+// the sign of the result is always exact and the magnitude an approximation. inputs are widened to
+// f64 first, which is lossless for every vector family in the crate. like the paper this assumes
+// nothing overflows or underflows
+
+const EPSILON: f64 = f64::EPSILON / 2.0;
+const RESULT_BOUND: f64 = (3.0 + 8.0 * EPSILON) * EPSILON;
+const CCW_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const CCW_BOUND_B: f64 = (2.0 + 12.0 * EPSILON) * EPSILON;
+const CCW_BOUND_C: f64 = (9.0 + 64.0 * EPSILON) * EPSILON * EPSILON;
+const O3D_BOUND: f64 = (7.0 + 56.0 * EPSILON) * EPSILON;
+const O3D_BOUND_B: f64 = (3.0 + 28.0 * EPSILON) * EPSILON;
+const ICC_BOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+const ICC_BOUND_B: f64 = (4.0 + 48.0 * EPSILON) * EPSILON;
+const ISP_BOUND: f64 = (16.0 + 224.0 * EPSILON) * EPSILON;
+const ISP_BOUND_B: f64 = (5.0 + 72.0 * EPSILON) * EPSILON;
+
+// positive when a, b, c wind counter-clockwise, negative clockwise and zero when collinear
+pub fn orient2d<P: Into<DVec2>>(a: P, b: P, c: P) -> f64 {
+    let (a, b, c) = (a.into(), b.into(), c.into());
+    let (acx, bcx, acy, bcy) = (a.x() - c.x(), b.x() - c.x(), a.y() - c.y(), b.y() - c.y());
+    let (left, right) = (acx * bcy, acy * bcx);
+    let det = left - right;
+    let permanent = left.abs() + right.abs();
+    if det.abs() > CCW_BOUND * permanent {
+        return det;
+    }
+    let stage_b = sub(&exact_product(acx, bcy), &exact_product(acy, bcx));
+    let det = estimate(&stage_b);
+    if det.abs() >= CCW_BOUND_B * permanent {
+        return det;
+    }
+    let (acx_tail, bcx_tail) = (difference_tail(a.x(), c.x(), acx), difference_tail(b.x(), c.x(), bcx));
+    let (acy_tail, bcy_tail) = (difference_tail(a.y(), c.y(), acy), difference_tail(b.y(), c.y(), bcy));
+    if acx_tail == 0.0 && bcx_tail == 0.0 && acy_tail == 0.0 && bcy_tail == 0.0 {
+        return det;
+    }
+    let bound = CCW_BOUND_C * permanent + RESULT_BOUND * det.abs();
+    let det = det + ((acx * bcy_tail + bcy * acx_tail) - (acy * bcx_tail + bcx * acy_tail));
+    if det.abs() >= bound {
+        return det;
+    }
+    // the remaining terms of (acx + acx_tail)(bcy + bcy_tail) - (acy + acy_tail)(bcx + bcx_tail)
+    let stage_d = [(acx_tail, bcy, acy_tail, bcx), (acx, bcy_tail, acy, bcx_tail), (acx_tail, bcy_tail, acy_tail, bcx_tail)]
+        .into_iter()
+        .fold(stage_b, |det, (p, q, r, s)| sum(&det, &sub(&exact_product(p, q), &exact_product(r, s))));
+    most_significant(&stage_d)
+}
+
+// positive when d lies below the plane through a, b, c, where below is the side from which
+// a, b, c appear clockwise. zero when coplanar
+pub fn orient3d<P: Into<DVec3>>(a: P, b: P, c: P, d: P) -> f64 {
+    let (a, b, c, d) = (a.into(), b.into(), c.into(), d.into());
+    let (ad, bd, cd) = (a - d, b - d, c - d);
+    let det = ad.dot(&bd.cross(&cd));
+    let permanent = permanent3(ad, bd, cd);
+    if det.abs() > O3D_BOUND * permanent {
+        return det;
+    }
+    let exact = |[ad, bd, cd]: [[Vec<f64>; 3]; 3]| triple_product(&ad, &bd, &cd);
+    adaptive([a, b, c], d, O3D_BOUND_B * permanent, exact)
+}
+
+// positive when d lies inside the circle through a, b, c, which must wind counter-clockwise
+// (the sign flips otherwise). zero when the four points are cocircular
+pub fn incircle<P: Into<DVec2>>(a: P, b: P, c: P, d: P) -> f64 {
+    let (a, b, c, d) = (a.into(), b.into(), c.into(), d.into());
+    let (ad, bd, cd) = (a - d, b - d, c - d);
+    let (bc, ca, ab) = (bd.perp_dot(&cd), cd.perp_dot(&ad), ad.perp_dot(&bd));
+    let (alift, blift, clift) = (ad.magnitude_squared(), bd.magnitude_squared(), cd.magnitude_squared());
+    let det = alift * bc + blift * ca + clift * ab;
+    let cross_permanent = |p: DVec2, q: DVec2| (p.x() * q.y()).abs() + (q.x() * p.y()).abs();
+    let permanent = alift * cross_permanent(bd, cd) + blift * cross_permanent(cd, ad) + clift * cross_permanent(ad, bd);
+    if det.abs() > ICC_BOUND * permanent {
+        return det;
+    }
+    let exact = |[ad, bd, cd]: [[Vec<f64>; 2]; 3]| {
+        let lift = |p: &[Vec<f64>]| sum(&product(&p[0], &p[0]), &product(&p[1], &p[1]));
+        let cross = |p: &[Vec<f64>], q: &[Vec<f64>]| sub(&product(&p[0], &q[1]), &product(&q[0], &p[1]));
+        sum(
+            &sum(&product(&lift(&ad), &cross(&bd, &cd)), &product(&lift(&bd), &cross(&cd, &ad))),
+            &product(&lift(&cd), &cross(&ad, &bd)),
+        )
+    };
+    adaptive([a, b, c], d, ICC_BOUND_B * permanent, exact)
+}
+
+// positive when e lies inside the sphere through a, b, c, d, which must have orient3d(a, b, c, d)
+// positive (the sign flips otherwise). zero when the five points are cospherical
+pub fn insphere<P: Into<DVec3>>(a: P, b: P, c: P, d: P, e: P) -> f64 {
+    let (a, b, c, d, e) = (a.into(), b.into(), c.into(), d.into(), e.into());
+    let [ae, be, ce, de] = [a - e, b - e, c - e, d - e];
+    let minor = |p: DVec3, q: DVec3, r: DVec3| p.dot(&q.cross(&r));
+    let (alift, blift, clift, dlift) = (ae.magnitude_squared(), be.magnitude_squared(), ce.magnitude_squared(), de.magnitude_squared());
+    let det = (dlift * minor(ae, be, ce) - clift * minor(de, ae, be)) + (blift * minor(ce, de, ae) - alift * minor(be, ce, de));
+    let permanent = (dlift * permanent3(ae, be, ce) + clift * permanent3(de, ae, be))
+        + (blift * permanent3(ce, de, ae) + alift * permanent3(be, ce, de));
+    if det.abs() > ISP_BOUND * permanent {
+        return det;
+    }
+    let exact = |[ae, be, ce, de]: [[Vec<f64>; 3]; 4]| {
+        let lift = |p: &[Vec<f64>]| sum(&sum(&product(&p[0], &p[0]), &product(&p[1], &p[1])), &product(&p[2], &p[2]));
+        // cofactor expansion along the lifted column, alternating sign
+        sub(
+            &sum(&product(&lift(&de), &triple_product(&ae, &be, &ce)), &product(&lift(&be), &triple_product(&ce, &de, &ae))),
+            &sum(&product(&lift(&ce), &triple_product(&de, &ae, &be)), &product(&lift(&ae), &triple_product(&be, &ce, &de))),
+        )
+    };
+    adaptive([a, b, c, d], e, ISP_BOUND_B * permanent, exact)
+}
+
+// stages b and d for the determinant `exact` of the differences of points from origin. stage b
+// feeds it the rounded differences, stage d the exact ones
+fn adaptive<const N: usize, const M: usize>(points: [VecN<f64, N>; M], origin: VecN<f64, N>, bound: f64, exact: impl Fn([[Vec<f64>; N]; M]) -> Vec<f64>) -> f64 {
+    let rounded = points.map(|p| (p - origin).0.map(|val| sub(&[val], &[])));
+    let stage_b = estimate(&exact(rounded));
+    if stage_b.abs() >= bound {
+        return stage_b;
+    }
+    let differences = points.map(|p| exact_difference(&p.0, &origin.0));
+    if differences.iter().flatten().all(|difference| difference.len() <= 1) {
+        return stage_b;
+    }
+    most_significant(&exact(differences))
+}
+
+// the determinant of the rows p, q, r with every product made positive, bounding its rounding error
+fn permanent3(p: DVec3, q: DVec3, r: DVec3) -> f64 {
+    let (p, q, r) = (p.abs(), q.abs(), r.abs());
+    p.x() * (q.y() * r.z() + q.z() * r.y()) + p.y() * (q.z() * r.x() + q.x() * r.z()) + p.z() * (q.x() * r.y() + q.y() * r.x())
+}
+
+// expansions are lists of non-overlapping f64 components in increasing magnitude whose exact
+// sum is the represented value, with zero components left out
+
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    (x, (a - a_virtual) + (b - b_virtual))
+}
+
+// fused multiply add rounds once, so it recovers the rounding error of the product exactly
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    (x, a.mul_add(b, -x))
+}
+
+fn grow(expansion: &[f64], val: f64) -> Vec<f64> {
+    let mut out = Vec::with_capacity(expansion.len() + 1);
+    let mut q = val;
+    for &component in expansion {
+        let (sum, error) = two_sum(q, component);
+        if error != 0.0 {
+            out.push(error);
+        }
+        q = sum;
+    }
+    if q != 0.0 {
+        out.push(q);
+    }
+    out
+}
+
+fn sum(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(e.to_vec(), |acc, &val| grow(&acc, val))
+}
+
+fn sub(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let negated: Vec<f64> = f.iter().map(|val| -val).collect();
+    sum(e, &negated)
+}
+
+fn scale(expansion: &[f64], b: f64) -> Vec<f64> {
+    let mut out = Vec::with_capacity(expansion.len() * 2);
+    let Some((&first, rest)) = expansion.split_first() else {
+        return out;
+    };
+    let (mut q, error) = two_product(first, b);
+    if error != 0.0 {
+        out.push(error);
+    }
+    for &component in rest {
+        let (high, low) = two_product(component, b);
+        let (partial, error) = two_sum(q, low);
+        if error != 0.0 {
+            out.push(error);
+        }
+        let (total, error) = two_sum(high, partial);
+        if error != 0.0 {
+            out.push(error);
+        }
+        q = total;
+    }
+    if q != 0.0 {
+        out.push(q);
+    }
+    out
+}
+
+fn product(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(Vec::new(), |acc, &val| sum(&acc, &scale(e, val)))
+}
+
+// the rounding error of a - b given its rounded result
+fn difference_tail(a: f64, b: f64, x: f64) -> f64 {
+    let b_virtual = a - x;
+    let a_virtual = x + b_virtual;
+    (a - a_virtual) + (b_virtual - b)
+}
+
+fn exact_product(a: f64, b: f64) -> Vec<f64> {
+    let (x, error) = two_product(a, b);
+    [error, x].into_iter().filter(|&val| val != 0.0).collect()
+}
+
+// the components summed in f64, the sign is still exact
+fn estimate(expansion: &[f64]) -> f64 {
+    expansion.iter().sum()
+}
+
+fn most_significant(expansion: &[f64]) -> f64 {
+    expansion.last().copied().unwrap_or(0.0)
+}
+
+fn exact_difference<const N: usize>(p: &[f64; N], q: &[f64; N]) -> [Vec<f64>; N] {
+    std::array::from_fn(|i| sub(&[p[i]], &[q[i]]))
+}
+
+// p . (q x r) with every coordinate an expansion
+fn triple_product(p: &[Vec<f64>; 3], q: &[Vec<f64>; 3], r: &[Vec<f64>; 3]) -> Vec<f64> {
+    let cross = |i: usize, j: usize| sub(&product(&q[i], &r[j]), &product(&q[j], &r[i]));
+    sum(&sum(&product(&p[0], &cross(1, 2)), &product(&p[1], &cross(2, 0))), &product(&p[2], &cross(0, 1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IVec2, Vec2, Vec3};
+
+    #[test]
+    fn predicates_orient2d_near_collinear() {
+        assert!(orient2d(Vec2::new(), Vec2::x_axis(), Vec2::y_axis()) > 0.0);
+        assert!(orient2d(IVec2::new(), IVec2::y_axis(), IVec2::x_axis()) < 0.0);
+        assert_eq!(orient2d(DVec2::splat(0.5), DVec2::splat(12.0), DVec2::splat(24.0)), 0.0);
+        // nudging a off the line y = x by k ulps, the exact determinant is -12 * k * ulp
+        let (b, c) = (DVec2::splat(12.0), DVec2::splat(24.0));
+        let mut naive_wrong = 0;
+        for k in 1..256 {
+            let a = DVec2::from_xy(0.5 + k as f64 * f64::EPSILON / 2.0, 0.5);
+            assert!(orient2d(a, b, c) < 0.0, "k = {k}");
+            assert!(orient2d(b, a, c) > 0.0, "k = {k}");
+            let (ac, bc) = (a - c, b - c);
+            naive_wrong += (ac.perp_dot(&bc) >= 0.0) as i32;
+        }
+        // the plain evaluation really does get these wrong
+        assert!(naive_wrong > 0);
+    }
+    #[test]
+    fn predicates_orient3d() {
+        let (a, b, c) = (Vec3::new(), Vec3::x_axis(), Vec3::y_axis());
+        assert!(orient3d(a, b, c, -Vec3::z_axis()) > 0.0);
+        assert!(orient3d(a, b, c, Vec3::z_axis()) < 0.0);
+        assert_eq!(orient3d(a, b, c, Vec3::from_xyz(3.0, -7.0, 0.0)), 0.0);
+        // points on z = x + y, where rounding x + y leaves d off the plane by exactly the error of the sum
+        let (a, b, c) = (DVec3::new(), DVec3::from_xyz(1.0, 0.0, 1.0), DVec3::from_xyz(0.0, 1.0, 1.0));
+        let mut seed = 7u64;
+        for _ in 0..200 {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            let (x, y) = ((seed >> 11) as f64 / (1u64 << 40) as f64, (seed >> 20) as f64 / (1u64 << 30) as f64);
+            let (rounded, error) = two_sum(x, y);
+            let side = orient3d(a, b, c, DVec3::from_xyz(x, y, rounded));
+            if error == 0.0 { assert_eq!(side, 0.0) } else { assert_eq!(side.signum(), error.signum()) }
+        }
+    }
+    #[test]
+    fn predicates_incircle() {
+        let (a, b, c) = (DVec2::x_axis(), DVec2::y_axis(), -DVec2::x_axis());
+        assert_eq!(incircle(a, b, c, -DVec2::y_axis()), 0.0);
+        let ulp = f64::EPSILON;
+        assert!(incircle(a, b, c, DVec2::from_xy(0.0, -1.0 + ulp)) > 0.0);
+        assert!(incircle(a, b, c, DVec2::from_xy(0.0, -1.0 - ulp)) < 0.0);
+        assert!(incircle(c, b, a, DVec2::from_xy(0.0, -1.0 + ulp)) < 0.0);
+        assert!(incircle(Vec2::x_axis(), Vec2::y_axis(), -Vec2::x_axis(), Vec2::new()) > 0.0);
+    }
+    #[test]
+    fn predicates_insphere() {
+        let (a, b, c, d) = (DVec3::x_axis(), DVec3::y_axis(), -DVec3::x_axis(), DVec3::z_axis());
+        let (a, b) = if orient3d(a, b, c, d) > 0.0 { (a, b) } else { (b, a) };
+        assert_eq!(insphere(a, b, c, d, -DVec3::z_axis()), 0.0);
+        let ulp = f64::EPSILON;
+        assert!(insphere(a, b, c, d, DVec3::from_xyz(0.0, 0.0, -1.0 + ulp)) > 0.0);
+        assert!(insphere(a, b, c, d, DVec3::from_xyz(0.0, 0.0, -1.0 - ulp)) < 0.0);
+        assert!(insphere(b, a, c, d, DVec3::from_xyz(0.0, 0.0, -1.0 + ulp)) < 0.0);
+        assert!(insphere(a, b, c, d, DVec3::new()) > 0.0);
+        assert!(insphere(a, b, c, d, DVec3::splat(2.0)) < 0.0);
+    }
+    #[test]
+    fn predicates_stages() {
+        // near degenerate inputs whose differences don't round exactly, so every stage gets a turn.
+        // the staged sign must match the determinant of the exact differences computed outright
+        let sign = |val: f64| (val > 0.0) as i32 - (val < 0.0) as i32;
+        let mut seed = 11u64;
+        let mut random = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 11) as f64 / (1u64 << 53) as f64
+        };
+        for _ in 0..500 {
+            let (a, b) = (DVec2::from_xy(random(), random()), DVec2::from_xy(random() + 3.0, random() * 7.0));
+            let t = random();
+            let c = a + (b - a) * t + DVec2::from_xy(random(), random()) * f64::EPSILON * (random() - 0.5);
+            let [ac, bc] = [a, b].map(|p| exact_difference(&p.0, &c.0));
+            let exact = sub(&product(&ac[0], &bc[1]), &product(&ac[1], &bc[0]));
+            assert_eq!(sign(orient2d(a, b, c)), sign(most_significant(&exact)));
+            let d = DVec2::from_xy(random(), random());
+            let (centre, radius) = (d * 0.5, random() + 0.5);
+            let on_circle = |angle: f64| centre + DVec2::from_xy(angle.cos(), angle.sin()) * radius;
+            let (p, q, r, s) = (on_circle(0.3), on_circle(2.0), on_circle(4.0), on_circle(random() * 6.0));
+            let [pd, qd, rd] = [p, q, r].map(|point| exact_difference(&point.0, &s.0));
+            let lift = |e: &[Vec<f64>]| sum(&product(&e[0], &e[0]), &product(&e[1], &e[1]));
+            let cross = |e: &[Vec<f64>], f: &[Vec<f64>]| sub(&product(&e[0], &f[1]), &product(&f[0], &e[1]));
+            let exact = sum(&sum(&product(&lift(&pd), &cross(&qd, &rd)), &product(&lift(&qd), &cross(&rd, &pd))), &product(&lift(&rd), &cross(&pd, &qd)));
+            assert_eq!(sign(incircle(p, q, r, s)), sign(most_significant(&exact)));
+        }
+    }
+    #[test]
+    fn predicates_expansion() {
+        // 1 + 2^-60 - 1 is lost in f64 but not in an expansion
+        let e = sum(&grow(&[1.0], 2f64.powi(-60)), &[-1.0]);
+        assert_eq!(e, vec![2f64.powi(-60)]);
+        // (1 + 2^-60)^2 = 1 + 2^-59 + 2^-120 needs three doubles
+        let square = product(&[2f64.powi(-60), 1.0], &[2f64.powi(-60), 1.0]);
+        assert_eq!(most_significant(&square), 1.0);
+        assert_eq!(sub(&square, &[2f64.powi(-59), 1.0]), vec![2f64.powi(-120)]);
+    }
+}
@@ -0,0 +1,332 @@
+use std::ops::{Mul,Neg};
+use crate::{Scalar, Float, VecN, MatN};
+
+// stored as [x, y, z, w] with w the real part
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Quaternion<T>(pub(crate) [T; 4]);
+
+pub type Quat = Quaternion<f32>;
+pub type DQuat = Quaternion<f64>;
+
+// XYZ means rotate about x, then the new y, then the new z (intrinsic), i.e. qx * qy * qz
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EulerRot {
+    XYZ, XZY, YXZ, YZX, ZXY, ZYX,
+    XYX, XZX, YXY, YZY, ZXZ, ZYZ,
+}
+
+impl EulerRot {
+    pub fn axes(&self) -> [usize; 3] {
+        match self {
+            EulerRot::XYZ => [0, 1, 2],
+            EulerRot::XZY => [0, 2, 1],
+            EulerRot::YXZ => [1, 0, 2],
+            EulerRot::YZX => [1, 2, 0],
+            EulerRot::ZXY => [2, 0, 1],
+            EulerRot::ZYX => [2, 1, 0],
+            EulerRot::XYX => [0, 1, 0],
+            EulerRot::XZX => [0, 2, 0],
+            EulerRot::YXY => [1, 0, 1],
+            EulerRot::YZY => [1, 2, 1],
+            EulerRot::ZXZ => [2, 0, 2],
+            EulerRot::ZYZ => [2, 1, 2],
+        }
+    }
+}
+
+impl<T: Scalar> Quaternion<T> {
+    pub fn identity() -> Self {
+        Quaternion([T::ZERO, T::ZERO, T::ZERO, T::ONE])
+    }
+    pub fn from_xyzw(x: T, y: T, z: T, w: T) -> Self {
+        Quaternion([x, y, z, w])
+    }
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+    pub fn z(&self) -> T {
+        self.0[2]
+    }
+    pub fn w(&self) -> T {
+        self.0[3]
+    }
+    // the imaginary part
+    pub fn xyz(&self) -> VecN<T, 3> {
+        VecN([self.0[0], self.0[1], self.0[2]])
+    }
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+    pub fn to_vec4(&self) -> VecN<T, 4> {
+        VecN(self.0)
+    }
+    pub fn dot(&self, other: &Self) -> T {
+        self.to_vec4().dot(&other.to_vec4())
+    }
+}
+
+impl<T: Float> Quaternion<T> {
+    pub fn from_axis_angle(axis: &VecN<T, 3>, angle: T) -> Self {
+        let half = angle / (T::ONE + T::ONE);
+        let [x, y, z] = (axis.normalise() * half.sin()).0;
+        Quaternion([x, y, z, half.cos()])
+    }
+    pub fn from_rotation_x(angle: T) -> Self {
+        Self::from_axis_angle(&VecN::<T, 3>::x_axis(), angle)
+    }
+    pub fn from_rotation_y(angle: T) -> Self {
+        Self::from_axis_angle(&VecN::<T, 3>::y_axis(), angle)
+    }
+    pub fn from_rotation_z(angle: T) -> Self {
+        Self::from_axis_angle(&VecN::<T, 3>::z_axis(), angle)
+    }
+    // shortest rotation taking the unit vector `from` onto the unit vector `to`
+    pub fn from_rotation_arc(from: &VecN<T, 3>, to: &VecN<T, 3>) -> Self {
+        let dot = from.dot(to);
+        if dot < T::EPSILON - T::ONE {
+            // opposite vectors, any axis perpendicular to `from` works
+            let axis = from.cross(&VecN::<T, 3>::x_axis());
+            let axis = if axis.magnitude() < T::EPSILON.sqrt() { from.cross(&VecN::<T, 3>::y_axis()) } else { axis };
+            return Self::from_axis_angle(&axis, T::PI);
+        }
+        let axis = from.cross(to);
+        Quaternion(axis.extend(T::ONE + dot).0).normalise()
+    }
+    pub fn from_euler(order: EulerRot, a: T, b: T, c: T) -> Self {
+        let [i, j, k] = order.axes();
+        let rotation = |index, angle| Self::from_axis_angle(&VecN::axis(index), angle);
+        rotation(i, a) * rotation(j, b) * rotation(k, c)
+    }
+    pub fn to_euler(&self, order: EulerRot) -> (T, T, T) {
+        // bernardes & viollet 2022, formulated for extrinsic rotations so run it on the reversed sequence
+        let [k, j, i] = order.axes();
+        let proper = i == k;
+        let k = if proper { 3 - i - j } else { k };
+        let sign = if (i + 1) % 3 == j { T::ONE } else { -T::ONE };
+        let q = self.0;
+        let (a, b, c, d) = if proper {
+            (q[3], q[i], q[j], q[k] * sign)
+        } else {
+            (q[3] - q[j], q[i] + q[k] * sign, q[j] + q[3], q[k] * sign - q[i])
+        };
+        let two = T::ONE + T::ONE;
+        let hypot = |x: T, y: T| (x * x + y * y).sqrt();
+        let mut second = two * hypot(c, d).atan2(hypot(a, b));
+        let half_sum = b.atan2(a);
+        let half_diff = d.atan2(c);
+        let tolerance = T::EPSILON.sqrt();
+        let (mut first, mut third);
+        if second.abs() <= tolerance {
+            // gimbal lock, only the sum (or difference) of the outer angles is defined so put it all in one
+            (first, third) = (two * half_sum, T::ZERO);
+        } else if (second - T::PI).abs() <= tolerance {
+            (first, third) = (-two * half_diff, T::ZERO);
+        } else {
+            (first, third) = (half_sum - half_diff, half_sum + half_diff);
+        }
+        if !proper {
+            third *= sign;
+            second -= T::PI / two;
+        }
+        std::mem::swap(&mut first, &mut third);
+        (first, second, third)
+    }
+    pub fn to_axis_angle(&self) -> (VecN<T, 3>, T) {
+        let quat = self.normalise();
+        let angle = (T::ONE + T::ONE) * quat.xyz().magnitude().atan2(quat.w());
+        // the identity has no meaningful axis, chose x so the result is still usable
+        let axis = quat.xyz().normalise();
+        if axis == VecN::new() { (VecN::<T, 3>::x_axis(), angle) } else { (axis, angle) }
+    }
+    pub fn magnitude(&self) -> T {
+        self.to_vec4().magnitude()
+    }
+    pub fn normalise(&self) -> Self {
+        Quaternion(self.to_vec4().normalise().0)
+    }
+    pub fn conjugate(&self) -> Self {
+        Quaternion([-self.0[0], -self.0[1], -self.0[2], self.0[3]])
+    }
+    pub fn inverse(&self) -> Self {
+        let norm = self.dot(self);
+        Quaternion(self.conjugate().0.map(|val| val / norm))
+    }
+    pub fn mul_vec3(&self, vector: &VecN<T, 3>) -> VecN<T, 3> {
+        // v + 2w(u x v) + 2u x (u x v), avoids building the full sandwich product
+        let u = self.xyz();
+        let two = T::ONE + T::ONE;
+        let uv = u.cross(vector);
+        let uuv = u.cross(&uv);
+        *vector + uv * (two * self.w()) + uuv * two
+    }
+    pub fn nlerp(&self, end: &Self, t: T) -> Self {
+        // flip the end onto the same hemisphere so the shortest path is taken
+        let end = if self.dot(end) < T::ZERO { -end } else { *end };
+        let start = self.to_vec4();
+        Quaternion((start + (end.to_vec4() - start) * t).0).normalise()
+    }
+    pub fn slerp(&self, end: &Self, t: T) -> Self {
+        let (end, dot) = if self.dot(end) < T::ZERO { (-end, -self.dot(end)) } else { (*end, self.dot(end)) };
+        // nearly parallel, sin(angle) would be ~0 so fall back to nlerp
+        if dot > T::ONE - T::EPSILON.sqrt() {
+            return self.nlerp(&end, t);
+        }
+        let angle = dot.acos();
+        let start_scale = ((T::ONE - t) * angle).sin() / angle.sin();
+        let end_scale = (t * angle).sin() / angle.sin();
+        Quaternion((self.to_vec4() * start_scale + end.to_vec4() * end_scale).0)
+    }
+    pub fn to_mat3(&self) -> MatN<T, 3> {
+        let [x, y, z, w] = self.0;
+        let two = T::ONE + T::ONE;
+        let (x2, y2, z2) = (x * two, y * two, z * two);
+        let (xx, xy, xz) = (x * x2, x * y2, x * z2);
+        let (yy, yz, zz) = (y * y2, y * z2, z * z2);
+        let (wx, wy, wz) = (w * x2, w * y2, w * z2);
+        MatN([
+            [T::ONE - (yy + zz), xy + wz, xz - wy],
+            [xy - wz, T::ONE - (xx + zz), yz + wx],
+            [xz + wy, yz - wx, T::ONE - (xx + yy)],
+        ])
+    }
+    pub fn to_mat4(&self) -> MatN<T, 4> {
+        let [x, y, z] = self.to_mat3().0.map(|col| VecN(col).xyz0().0);
+        MatN([x, y, z, VecN::<T, 4>::w_axis().0])
+    }
+}
+
+impl<T: Float> Mul<&Quaternion<T>> for &Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn mul(self, other: &Quaternion<T>) -> Self::Output {
+        // hamilton product, applies `other` first then `self`
+        let [x1, y1, z1, w1] = self.0;
+        let [x2, y2, z2, w2] = other.0;
+        Quaternion([
+            w1 * x2 + x1 * w2 + y1 * z2 - z1 * y2,
+            w1 * y2 - x1 * z2 + y1 * w2 + z1 * x2,
+            w1 * z2 + x1 * y2 - y1 * x2 + z1 * w2,
+            w1 * w2 - x1 * x2 - y1 * y2 - z1 * z2,
+        ])
+    }
+}
+impl<T: Float> Mul<Quaternion<T>> for Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn mul(self, other: Quaternion<T>) -> Self::Output {
+        &self * &other
+    }
+}
+impl<T: Float> Mul<&VecN<T, 3>> for &Quaternion<T> {
+    type Output = VecN<T, 3>;
+    fn mul(self, vector: &VecN<T, 3>) -> Self::Output {
+        self.mul_vec3(vector)
+    }
+}
+impl<T: Float> Mul<VecN<T, 3>> for Quaternion<T> {
+    type Output = VecN<T, 3>;
+    fn mul(self, vector: VecN<T, 3>) -> Self::Output {
+        self.mul_vec3(&vector)
+    }
+}
+impl<T: Float> Neg for &Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn neg(self) -> Self::Output {
+        Quaternion(self.0.map(|val| -val))
+    }
+}
+impl<T: Float> Neg for Quaternion<T> {
+    type Output = Quaternion<T>;
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+impl<T: Float> From<Quaternion<T>> for MatN<T, 3> {
+    fn from(quat: Quaternion<T>) -> Self {
+        quat.to_mat3()
+    }
+}
+impl<T: Float> From<Quaternion<T>> for MatN<T, 4> {
+    fn from(quat: Quaternion<T>) -> Self {
+        quat.to_mat4()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_vec_approx_eq, DVec3, Mat4, Vec3};
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    fn assert_same_rotation(a: &DQuat, b: &DQuat) {
+        // q and -q are the same rotation
+        assert!(1.0 - a.dot(b).abs() < 1e-9, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn quat_axis_angle() {
+        let q = Quat::from_axis_angle(&Vec3::z_axis(), FRAC_PI_2);
+        assert_vec_approx_eq!(q.mul_vec3(&Vec3::x_axis()), Vec3::y_axis(), epsilon = 1e-5);
+        assert_vec_approx_eq!(Quat::from_rotation_x(FRAC_PI_2) * Vec3::y_axis(), Vec3::z_axis(), epsilon = 1e-5);
+        let (axis, angle) = Quat::from_axis_angle(&Vec3::from_xyz(0.0, 3.0, 0.0), 1.0).to_axis_angle();
+        assert_vec_approx_eq!(axis, Vec3::y_axis(), epsilon = 1e-5);
+        assert!((angle - 1.0).abs() < 1e-6);
+    }
+    #[test]
+    fn quat_rotation_arc() {
+        let from = Vec3::from_xyz(1.0, 2.0, 3.0).normalise();
+        let to = Vec3::from_xyz(-3.0, 1.0, 0.5).normalise();
+        assert_vec_approx_eq!(Quat::from_rotation_arc(&from, &to).mul_vec3(&from), to, epsilon = 1e-5);
+        assert_vec_approx_eq!(Quat::from_rotation_arc(&Vec3::x_axis(), &-Vec3::x_axis()).mul_vec3(&Vec3::x_axis()), -Vec3::x_axis(), epsilon = 1e-5);
+        assert_eq!(Quat::from_rotation_arc(&Vec3::y_axis(), &Vec3::y_axis()), Quat::identity());
+    }
+    #[test]
+    fn quat_mul_inverse() {
+        let a = Quat::from_rotation_x(0.3);
+        let b = Quat::from_rotation_y(-1.2);
+        let v = Vec3::from_xyz(1.0, -2.0, 0.5);
+        assert_vec_approx_eq!((a * b).mul_vec3(&v), a.mul_vec3(&b.mul_vec3(&v)), epsilon = 1e-5);
+        assert_vec_approx_eq!(a.inverse().mul_vec3(&a.mul_vec3(&v)), v, epsilon = 1e-5);
+        assert_eq!(a.conjugate().conjugate(), a);
+        let scaled = Quaternion(a.0.map(|val| val * 2.0));
+        assert!(((scaled * scaled.inverse()).to_vec4() - Quat::identity().to_vec4()).magnitude() < 1e-6);
+    }
+    #[test]
+    fn quat_euler() {
+        let orders = [
+            EulerRot::XYZ, EulerRot::XZY, EulerRot::YXZ, EulerRot::YZX, EulerRot::ZXY, EulerRot::ZYX,
+            EulerRot::XYX, EulerRot::XZX, EulerRot::YXY, EulerRot::YZY, EulerRot::ZXZ, EulerRot::ZYZ,
+        ];
+        for order in orders {
+            let [i, j, k] = order.axes();
+            let (a, b, c) = (0.4, if i == k { 1.1 } else { -0.7 }, 2.3);
+            let q = DQuat::from_euler(order, a, b, c);
+            let expected = DQuat::from_axis_angle(&DVec3::axis(i), a) * DQuat::from_axis_angle(&DVec3::axis(j), b) * DQuat::from_axis_angle(&DVec3::axis(k), c);
+            assert_same_rotation(&q, &expected);
+            let (a2, b2, c2) = q.to_euler(order);
+            assert!((a - a2).abs() < 1e-9 && (b - b2).abs() < 1e-9 && (c - c2).abs() < 1e-9, "{order:?}: {:?}", (a2, b2, c2));
+            // gimbal lock still has to round trip as a rotation
+            let locked = DQuat::from_euler(order, a, if i == k { 0.0 } else { std::f64::consts::FRAC_PI_2 }, c);
+            let (a3, b3, c3) = locked.to_euler(order);
+            assert_same_rotation(&DQuat::from_euler(order, a3, b3, c3), &locked);
+        }
+    }
+    #[test]
+    fn quat_interpolation() {
+        let a = Quat::identity();
+        let b = Quat::from_rotation_z(FRAC_PI_2);
+        assert_vec_approx_eq!(a.slerp(&b, 0.5).mul_vec3(&Vec3::x_axis()), Vec3::from_xyz(1.0, 1.0, 0.0).normalise(), epsilon = 1e-5);
+        assert_vec_approx_eq!(a.nlerp(&b, 0.5).mul_vec3(&Vec3::x_axis()), Vec3::from_xyz(1.0, 1.0, 0.0).normalise(), epsilon = 1e-5);
+        assert_vec_approx_eq!(a.slerp(&-&b, 1.0).mul_vec3(&Vec3::x_axis()), Vec3::y_axis(), epsilon = 1e-5);
+        assert_vec_approx_eq!(a.slerp(&Quat::from_rotation_z(PI * 0.75), 1.0 / 3.0).mul_vec3(&Vec3::x_axis()), Quat::from_rotation_z(PI * 0.25).mul_vec3(&Vec3::x_axis()), epsilon = 1e-5);
+    }
+    #[test]
+    fn quat_matrix() {
+        let q = Quat::from_axis_angle(&Vec3::from_xyz(1.0, 1.0, 0.0), 0.8);
+        let v = Vec3::from_xyz(0.3, -1.0, 2.0);
+        assert_vec_approx_eq!(q.to_mat3() * v, q.mul_vec3(&v), epsilon = 1e-5);
+        assert_vec_approx_eq!(Mat4::from(Quat::from_rotation_y(0.5)).transform_vector3(&v), Mat4::from_rotation_y(0.5).transform_vector3(&v), epsilon = 1e-5);
+    }
+}
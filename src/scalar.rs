@@ -0,0 +1,117 @@
+use std::fmt::Debug;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+pub trait Scalar:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + Debug
+    + Default
+    + Sum
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+pub trait Signed: Scalar + Neg<Output = Self> {
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+pub trait Float: Signed {
+    const EPSILON: Self;
+    const PI: Self;
+    const INFINITY: Self;
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn acos(self) -> Self;
+    fn exp(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn fract(self) -> Self;
+    fn recip(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+}
+
+macro_rules! scalars {
+    ($zero:expr, $one:expr, $($T:ident),*) => {
+        $(
+            impl Scalar for $T {
+                const ZERO: Self = $zero;
+                const ONE: Self = $one;
+            }
+        )*
+    };
+}
+
+scalars!(0, 1, i32, u32);
+scalars!(0.0, 1.0, f32, f64);
+
+// forwards to the inherent methods of the primitive
+macro_rules! primitive_fns {
+    ($T:ident, $($fn_name:ident),*) => {
+        $(
+            fn $fn_name(self) -> Self {
+                $T::$fn_name(self)
+            }
+        )*
+    };
+}
+
+macro_rules! signeds {
+    ($($T:ident),*) => {
+        $(
+            impl Signed for $T {
+                primitive_fns!($T, abs, signum);
+            }
+        )*
+    };
+}
+
+signeds!(i32, f32, f64);
+
+macro_rules! floats {
+    ($($T:ident),*) => {
+        $(
+            impl Float for $T {
+                const EPSILON: Self = $T::EPSILON;
+                const PI: Self = std::$T::consts::PI;
+                const INFINITY: Self = $T::INFINITY;
+                primitive_fns!($T, sqrt, sin, cos, tan, acos, exp, floor, ceil, round, fract, recip);
+                fn powf(self, n: Self) -> Self {
+                    $T::powf(self, n)
+                }
+                fn atan2(self, other: Self) -> Self {
+                    $T::atan2(self, other)
+                }
+                fn is_nan(self) -> bool {
+                    $T::is_nan(self)
+                }
+                fn is_finite(self) -> bool {
+                    $T::is_finite(self)
+                }
+            }
+        )*
+    };
+}
+
+floats!(f32, f64);
+
+pub(crate) fn clamp_unit<T: Float>(t: T) -> T {
+    if t < T::ZERO { T::ZERO } else if t > T::ONE { T::ONE } else { t }
+}
@@ -0,0 +1,162 @@
+use std::fmt;
+use std::marker::PhantomData;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::{Error, IgnoredAny, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use crate::{Scalar, Vec3, Vec3A, Vec4, Vec4A, VecN};
+
+// vectors are compact sequences, [x, y, z]
+impl<T: Scalar + Serialize, const N: usize> Serialize for VecN<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for val in &self.0 {
+            tuple.serialize_element(val)?;
+        }
+        tuple.end()
+    }
+}
+
+struct SeqVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T: Scalar + Deserialize<'de>, const N: usize> Visitor<'de> for SeqVisitor<T, N> {
+    type Value = VecN<T, N>;
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a sequence of {N} numbers")
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut arr = [T::ZERO; N];
+        for (i, val) in arr.iter_mut().enumerate() {
+            *val = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(i, &self))?;
+        }
+        // self describing formats hand over the whole sequence, so reject anything left over
+        if seq.next_element::<IgnoredAny>()?.is_some() {
+            return Err(A::Error::invalid_length(N + 1, &self));
+        }
+        Ok(VecN(arr))
+    }
+}
+
+impl<'de, T: Scalar + Deserialize<'de>, const N: usize> Deserialize<'de> for VecN<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_tuple(N, SeqVisitor(PhantomData))
+    }
+}
+
+// the aligned vectors use the same sequence as the unaligned ones, without the padding lane
+macro_rules! aligned_serde {
+    ($(($Vec:ident, $Unaligned:ident)),*) => {
+        $(
+            impl Serialize for $Vec {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    $Unaligned::from(*self).serialize(serializer)
+                }
+            }
+            impl<'de> Deserialize<'de> for $Vec {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    $Unaligned::deserialize(deserializer).map($Vec::from)
+                }
+            }
+        )*
+    };
+}
+
+aligned_serde!((Vec3A, Vec3), (Vec4A, Vec4));
+
+// opt in with `#[serde(with = "math::serialize::named")]` to get {x, y, z} instead
+pub mod named {
+    use std::fmt;
+    use std::marker::PhantomData;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde::de::{Error, MapAccess, Visitor};
+    use serde::ser::SerializeStruct;
+    use crate::{Scalar, VecN};
+
+    const FIELDS: [&str; 4] = ["x", "y", "z", "w"];
+
+    pub fn serialize<T: Scalar + Serialize, const N: usize, S: Serializer>(vector: &VecN<T, N>, serializer: S) -> Result<S::Ok, S::Error> {
+        if N > FIELDS.len() {
+            return Err(serde::ser::Error::custom(format!("only vectors up to {} dimensions have named fields", FIELDS.len())));
+        }
+        let mut state = serializer.serialize_struct("VecN", N)?;
+        for (name, val) in FIELDS.iter().zip(&vector.0) {
+            state.serialize_field(name, val)?;
+        }
+        state.end()
+    }
+
+    struct MapVisitor<T, const N: usize>(PhantomData<T>);
+
+    impl<'de, T: Scalar + Deserialize<'de>, const N: usize> Visitor<'de> for MapVisitor<T, N> {
+        type Value = VecN<T, N>;
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a map with the fields {:?}", &FIELDS[..N.min(FIELDS.len())])
+        }
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            if N > FIELDS.len() {
+                return Err(A::Error::custom(format!("only vectors up to {} dimensions have named fields", FIELDS.len())));
+            }
+            let fields = &FIELDS[..N];
+            let mut arr = [None; N];
+            while let Some(key) = map.next_key::<String>()? {
+                let index = fields.iter().position(|field| *field == key).ok_or_else(|| A::Error::unknown_field(&key, fields))?;
+                if arr[index].is_some() {
+                    return Err(A::Error::duplicate_field(fields[index]));
+                }
+                arr[index] = Some(map.next_value()?);
+            }
+            for (i, val) in arr.iter().enumerate() {
+                if val.is_none() {
+                    return Err(A::Error::missing_field(fields[i]));
+                }
+            }
+            Ok(VecN(arr.map(|val| val.unwrap_or(T::ZERO))))
+        }
+    }
+
+    pub fn deserialize<'de, T: Scalar + Deserialize<'de>, const N: usize, D: Deserializer<'de>>(deserializer: D) -> Result<VecN<T, N>, D::Error> {
+        deserializer.deserialize_struct("VecN", &FIELDS[..N.min(FIELDS.len())], MapVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use crate::{Vec2, Vec3, Vec3A, IVec4, VecN};
+
+    #[test]
+    fn serde_sequence() {
+        let v = Vec3::from_xyz(1.0, 2.5, -3.0);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, "[1.0,2.5,-3.0]");
+        assert_eq!(serde_json::from_str::<Vec3>(&json).unwrap(), v);
+        assert_eq!(serde_json::from_str::<IVec4>("[1,2,3,4]").unwrap(), IVec4::from_xyzw(1, 2, 3, 4));
+        assert_eq!(serde_json::from_str::<VecN<f64, 6>>("[1,2,3,4,5,6]").unwrap(), VecN::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+        let aligned = Vec3A::from_xyz(1.0, 2.5, -3.0);
+        assert_eq!(serde_json::to_string(&aligned).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Vec3A>(&json).unwrap(), aligned);
+    }
+    #[test]
+    fn serde_sequence_length() {
+        let short = serde_json::from_str::<Vec3>("[1.0,2.0]").unwrap_err();
+        assert!(short.to_string().contains("invalid length 2"), "{short}");
+        let long = serde_json::from_str::<Vec2>("[1.0,2.0,3.0]").unwrap_err();
+        assert!(long.to_string().contains("invalid length 3"), "{long}");
+    }
+    #[test]
+    fn serde_named() {
+        #[derive(Serialize, Deserialize, PartialEq, Debug)]
+        struct Camera {
+            #[serde(with = "crate::serialize::named")]
+            position: Vec3,
+            target: Vec3,
+        }
+        let camera = Camera { position: Vec3::from_xyz(1.0, 2.0, 3.0), target: Vec3::new() };
+        let json = serde_json::to_string(&camera).unwrap();
+        assert_eq!(json, r#"{"position":{"x":1.0,"y":2.0,"z":3.0},"target":[0.0,0.0,0.0]}"#);
+        assert_eq!(serde_json::from_str::<Camera>(&json).unwrap(), camera);
+        let missing = serde_json::from_str::<Camera>(r#"{"position":{"x":1.0,"y":2.0},"target":[0.0,0.0,0.0]}"#).unwrap_err();
+        assert!(missing.to_string().contains("missing field `z`"), "{missing}");
+        let unknown = serde_json::from_str::<Camera>(r#"{"position":{"x":1.0,"y":2.0,"w":3.0},"target":[0.0,0.0,0.0]}"#).unwrap_err();
+        assert!(unknown.to_string().contains("unknown field `w`"), "{unknown}");
+    }
+}
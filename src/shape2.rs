@@ -0,0 +1,369 @@
+use crate::{Float, VecN};
+use crate::scalar::clamp_unit;
+
+// 2d primitives, generic over the float type with aliases like the 3d ones
+
+pub type Segment2f = Segment2<f32>;
+pub type Segment2d = Segment2<f64>;
+pub type Rectf = Rect<f32>;
+pub type Rectd = Rect<f64>;
+pub type Circlef = Circle<f32>;
+pub type Circled = Circle<f64>;
+pub type Polygonf = Polygon<f32>;
+pub type Polygond = Polygon<f64>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Orientation {
+    CounterClockwise,
+    Clockwise,
+    Collinear,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Segment2<T> {
+    pub start: VecN<T, 2>,
+    pub end: VecN<T, 2>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Rect<T> {
+    pub min: VecN<T, 2>,
+    pub max: VecN<T, 2>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Circle<T> {
+    pub center: VecN<T, 2>,
+    pub radius: T,
+}
+
+// a simple polygon, the last vertex connects back to the first
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Polygon<T> {
+    pub vertices: Vec<VecN<T, 2>>,
+}
+
+impl<T: Float> Segment2<T> {
+    pub fn new(start: VecN<T, 2>, end: VecN<T, 2>) -> Self {
+        Segment2 { start, end }
+    }
+    pub fn length(&self) -> T {
+        self.start.distance(&self.end)
+    }
+    pub fn closest_point(&self, point: &VecN<T, 2>) -> VecN<T, 2> {
+        let direction = self.end - self.start;
+        let length_squared = direction.magnitude_squared();
+        if length_squared == T::ZERO {
+            return self.start;
+        }
+        self.start + direction * clamp_unit((*point - self.start).dot(&direction) / length_squared)
+    }
+    // endpoints touching count as an intersection. collinear segments which overlap
+    // return the point of the overlap closest to self.start
+    pub fn intersect(&self, other: &Self) -> Option<VecN<T, 2>> {
+        let (d1, d2) = (self.end - self.start, other.end - other.start);
+        let offset = other.start - self.start;
+        let denom = d1.perp_dot(&d2);
+        if denom == T::ZERO {
+            if offset.perp_dot(&d1) != T::ZERO {
+                // parallel on different lines
+                return None;
+            }
+            let length_squared = d1.magnitude_squared();
+            if length_squared == T::ZERO {
+                return if other.closest_point(&self.start) == self.start { Some(self.start) } else { None };
+            }
+            // project other onto self and intersect the parameter ranges
+            let t0 = offset.dot(&d1) / length_squared;
+            let t1 = (other.end - self.start).dot(&d1) / length_squared;
+            let (low, high) = if t0 < t1 { (t0, t1) } else { (t1, t0) };
+            if high < T::ZERO || low > T::ONE {
+                return None;
+            }
+            let t = if low > T::ZERO { low } else { T::ZERO };
+            return Some(self.start + d1 * t);
+        }
+        let t = offset.perp_dot(&d2) / denom;
+        let u = offset.perp_dot(&d1) / denom;
+        if t < T::ZERO || t > T::ONE || u < T::ZERO || u > T::ONE {
+            return None;
+        }
+        Some(self.start + d1 * t)
+    }
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersect(other).is_some()
+    }
+}
+
+impl<T: Float> Rect<T> {
+    // the corners can be given in any order
+    pub fn new(a: VecN<T, 2>, b: VecN<T, 2>) -> Self {
+        Rect { min: a.min(&b), max: a.max(&b) }
+    }
+    pub fn from_center_size(center: VecN<T, 2>, size: VecN<T, 2>) -> Self {
+        let half = size.abs() / (T::ONE + T::ONE);
+        Rect { min: center - half, max: center + half }
+    }
+    pub fn center(&self) -> VecN<T, 2> {
+        (self.min + self.max) / (T::ONE + T::ONE)
+    }
+    pub fn size(&self) -> VecN<T, 2> {
+        self.max - self.min
+    }
+    pub fn area(&self) -> T {
+        self.size().product()
+    }
+    pub fn contains_point(&self, point: &VecN<T, 2>) -> bool {
+        (0..2).all(|i| point.0[i] >= self.min.0[i] && point.0[i] <= self.max.0[i])
+    }
+    pub fn closest_point(&self, point: &VecN<T, 2>) -> VecN<T, 2> {
+        point.clamp(&self.min, &self.max)
+    }
+    pub fn overlaps_rect(&self, other: &Self) -> bool {
+        (0..2).all(|i| self.min.0[i] <= other.max.0[i] && other.min.0[i] <= self.max.0[i])
+    }
+    // counter-clockwise from min
+    pub fn to_polygon(&self) -> Polygon<T> {
+        let (min, max) = (self.min, self.max);
+        Polygon::new(vec![min, VecN([max.0[0], min.0[1]]), max, VecN([min.0[0], max.0[1]])])
+    }
+}
+
+impl<T: Float> Circle<T> {
+    pub fn new(center: VecN<T, 2>, radius: T) -> Self {
+        Circle { center, radius }
+    }
+    pub fn area(&self) -> T {
+        T::PI * self.radius * self.radius
+    }
+    pub fn contains_point(&self, point: &VecN<T, 2>) -> bool {
+        self.center.distance_squared(point) <= self.radius * self.radius
+    }
+    // points inside are their own closest point, otherwise the nearest point on the circle
+    pub fn closest_point(&self, point: &VecN<T, 2>) -> VecN<T, 2> {
+        if self.contains_point(point) {
+            return *point;
+        }
+        self.center + (*point - self.center).with_length(self.radius)
+    }
+    pub fn overlaps_circle(&self, other: &Self) -> bool {
+        let radius = self.radius + other.radius;
+        self.center.distance_squared(&other.center) <= radius * radius
+    }
+    pub fn overlaps_rect(&self, rect: &Rect<T>) -> bool {
+        self.contains_point(&rect.closest_point(&self.center))
+    }
+    pub fn overlaps_segment(&self, segment: &Segment2<T>) -> bool {
+        self.contains_point(&segment.closest_point(&self.center))
+    }
+}
+
+impl<T: Float> Polygon<T> {
+    pub fn new(vertices: Vec<VecN<T, 2>>) -> Self {
+        Polygon { vertices }
+    }
+    pub fn edges(&self) -> impl Iterator<Item = Segment2<T>> + '_ {
+        let count = self.vertices.len();
+        (0..count).map(move |i| Segment2::new(self.vertices[i], self.vertices[(i + 1) % count]))
+    }
+    // shoelace formula, positive for counter-clockwise vertices
+    pub fn signed_area(&self) -> T {
+        self.edges().map(|edge| edge.start.perp_dot(&edge.end)).sum::<T>() / (T::ONE + T::ONE)
+    }
+    pub fn area(&self) -> T {
+        self.signed_area().abs()
+    }
+    pub fn orientation(&self) -> Orientation {
+        let area = self.signed_area();
+        if area > T::ZERO {
+            Orientation::CounterClockwise
+        } else if area < T::ZERO {
+            Orientation::Clockwise
+        } else {
+            Orientation::Collinear
+        }
+    }
+    // falls back to the vertex average when the area is zero, None when there are no vertices
+    pub fn centroid(&self) -> Option<VecN<T, 2>> {
+        if self.vertices.is_empty() {
+            return None;
+        }
+        let area = self.signed_area();
+        if area == T::ZERO {
+            let count = self.vertices.iter().fold(T::ZERO, |count, _| count + T::ONE);
+            return Some(self.vertices.iter().fold(VecN::new(), |sum, vertex| sum + *vertex) / count);
+        }
+        let weighted = self.edges().fold(VecN::new(), |sum, edge| {
+            sum + (edge.start + edge.end) * edge.start.perp_dot(&edge.end)
+        });
+        Some(weighted / (area * (T::ONE + T::ONE + T::ONE + T::ONE + T::ONE + T::ONE)))
+    }
+    // dan sunday's winding number, counting signed upward and downward edge crossings instead
+    // of summing angles. points exactly on the boundary may land either side
+    pub fn winding_number(&self, point: &VecN<T, 2>) -> i32 {
+        self.edges().fold(0, |winding, edge| {
+            let side = (edge.end - edge.start).perp_dot(&(*point - edge.start));
+            if edge.start.0[1] <= point.0[1] {
+                if edge.end.0[1] > point.0[1] && side > T::ZERO { winding + 1 } else { winding }
+            } else if edge.end.0[1] <= point.0[1] && side < T::ZERO {
+                winding - 1
+            } else {
+                winding
+            }
+        })
+    }
+    // non-zero rule, so the overlapping middle of a self-intersecting polygon is inside
+    pub fn contains_point(&self, point: &VecN<T, 2>) -> bool {
+        self.winding_number(point) != 0
+    }
+    // every turn goes the same way and the boundary only sweeps round once, so a
+    // self-intersecting star is not convex. collinear vertices are allowed
+    pub fn is_convex(&self) -> bool {
+        let count = self.vertices.len();
+        if count < 3 {
+            return false;
+        }
+        let (mut sign, mut x_flips) = (T::ZERO, 0);
+        let mut previous_dx = T::ZERO;
+        for i in 0..count {
+            let (a, b, c) = (self.vertices[i], self.vertices[(i + 1) % count], self.vertices[(i + 2) % count]);
+            let turn = (b - a).perp_dot(&(c - b));
+            if turn != T::ZERO {
+                if sign != T::ZERO && turn.signum() != sign {
+                    return false;
+                }
+                sign = turn.signum();
+            }
+            let dx = b.0[0] - a.0[0];
+            if dx != T::ZERO {
+                if previous_dx != T::ZERO && dx.signum() != previous_dx.signum() {
+                    x_flips += 1;
+                }
+                previous_dx = dx;
+            }
+        }
+        // the flip from the last edge back round to the first isn't counted in the loop
+        let first_dx = self.edges().map(|edge| edge.end.0[0] - edge.start.0[0]).find(|dx| *dx != T::ZERO);
+        if let Some(first_dx) = first_dx {
+            if first_dx.signum() != previous_dx.signum() {
+                x_flips += 1;
+            }
+        }
+        sign != T::ZERO && x_flips <= 2
+    }
+    // sutherland-hodgman, clip must be convex but can be wound either way
+    pub fn clip(&self, clip: &Self) -> Self {
+        let sign = match clip.orientation() {
+            Orientation::Clockwise => -T::ONE,
+            _ => T::ONE,
+        };
+        // the outward normal of each edge, to the right of a counter-clockwise boundary
+        clip.edges().fold(self.clone(), |polygon, edge| {
+            polygon.clip_half_plane(&edge.start, &(-(edge.end - edge.start).perp() * sign))
+        })
+    }
+    // keeps the part behind the line through point, on the opposite side to normal
+    pub fn clip_half_plane(&self, point: &VecN<T, 2>, normal: &VecN<T, 2>) -> Self {
+        let distance = |vertex: &VecN<T, 2>| normal.dot(&(*vertex - *point));
+        let crossing = |a: VecN<T, 2>, b: VecN<T, 2>| {
+            let (da, db) = (distance(&a), distance(&b));
+            a + (b - a) * (da / (da - db))
+        };
+        let mut output = Vec::with_capacity(self.vertices.len() + 1);
+        for (i, current) in self.vertices.iter().enumerate() {
+            let previous = self.vertices[(i + self.vertices.len() - 1) % self.vertices.len()];
+            let (previous_distance, current_distance) = (distance(&previous), distance(current));
+            // a vertex exactly on the line is its own crossing, so it isn't added twice
+            if (previous_distance < T::ZERO && current_distance > T::ZERO) || (previous_distance > T::ZERO && current_distance < T::ZERO) {
+                output.push(crossing(previous, *current));
+            }
+            if current_distance <= T::ZERO {
+                output.push(*current);
+            }
+        }
+        Polygon::new(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_vec_approx_eq, Vec2};
+
+    fn square() -> Polygonf {
+        Rect::new(Vec2::new(), Vec2::splat(2.0)).to_polygon()
+    }
+
+    #[test]
+    fn shape2_segment_intersect() {
+        let a = Segment2::new(Vec2::new(), Vec2::from_xy(2.0, 2.0));
+        assert_eq!(a.intersect(&Segment2::new(Vec2::from_xy(0.0, 2.0), Vec2::from_xy(2.0, 0.0))), Some(Vec2::splat(1.0)));
+        assert_eq!(a.intersect(&Segment2::new(Vec2::from_xy(2.0, 2.0), Vec2::from_xy(3.0, 0.0))), Some(Vec2::splat(2.0)));
+        assert_eq!(a.intersect(&Segment2::new(Vec2::from_xy(5.0, 0.0), Vec2::from_xy(4.0, 1.0))), None);
+        assert_eq!(a.intersect(&Segment2::new(Vec2::from_xy(0.0, 1.0), Vec2::from_xy(2.0, 3.0))), None);
+        // collinear
+        assert_eq!(a.intersect(&Segment2::new(Vec2::from_xy(3.0, 3.0), Vec2::from_xy(1.0, 1.0))), Some(Vec2::splat(1.0)));
+        assert_eq!(a.intersect(&Segment2::new(Vec2::from_xy(3.0, 3.0), Vec2::from_xy(4.0, 4.0))), None);
+        assert_eq!(a.closest_point(&Vec2::from_xy(2.0, 0.0)), Vec2::splat(1.0));
+    }
+    #[test]
+    fn shape2_rect_circle() {
+        let rect = Rect::from_center_size(Vec2::splat(1.0), Vec2::splat(2.0));
+        assert_eq!(rect, Rect::new(Vec2::splat(2.0), Vec2::new()));
+        assert_eq!((rect.area(), rect.center()), (4.0, Vec2::splat(1.0)));
+        assert!(rect.contains_point(&Vec2::from_xy(2.0, 0.5)) && !rect.contains_point(&Vec2::from_xy(2.1, 0.5)));
+        assert!(rect.overlaps_rect(&Rect::new(Vec2::splat(2.0), Vec2::splat(3.0))));
+        let circle = Circle::new(Vec2::from_xy(3.0, 1.0), 1.0);
+        assert!(circle.overlaps_rect(&rect) && !Circle::new(Vec2::splat(3.0), 1.0).overlaps_rect(&rect));
+        assert!(circle.overlaps_circle(&Circle::new(Vec2::from_xy(3.0, 3.0), 1.0)));
+        assert_eq!(circle.closest_point(&Vec2::from_xy(3.0, 5.0)), Vec2::from_xy(3.0, 2.0));
+        assert!(circle.overlaps_segment(&Segment2::new(Vec2::from_xy(2.0, 2.0), Vec2::from_xy(4.0, 2.0))));
+    }
+    #[test]
+    fn shape2_polygon_measure() {
+        let square = square();
+        assert_eq!((square.signed_area(), square.orientation()), (4.0, Orientation::CounterClockwise));
+        let mut reversed = square.clone();
+        reversed.vertices.reverse();
+        assert_eq!((reversed.signed_area(), reversed.orientation()), (-4.0, Orientation::Clockwise));
+        assert_eq!(square.centroid(), Some(Vec2::splat(1.0)));
+        let triangle = Polygon::new(vec![Vec2::new(), Vec2::from_xy(3.0, 0.0), Vec2::from_xy(0.0, 3.0)]);
+        assert_vec_approx_eq!(triangle.centroid().unwrap(), Vec2::splat(1.0));
+        let line = Polygon::new(vec![Vec2::new(), Vec2::from_xy(2.0, 0.0)]);
+        assert_eq!((line.orientation(), line.centroid()), (Orientation::Collinear, Some(Vec2::x_axis())));
+        assert_eq!(Polygonf::default().centroid(), None);
+    }
+    #[test]
+    fn shape2_polygon_contains_and_convex() {
+        // a u shape
+        let u = Polygon::new(vec![
+            Vec2::new(), Vec2::from_xy(3.0, 0.0), Vec2::from_xy(3.0, 3.0), Vec2::from_xy(2.0, 3.0),
+            Vec2::from_xy(2.0, 1.0), Vec2::from_xy(1.0, 1.0), Vec2::from_xy(1.0, 3.0), Vec2::from_xy(0.0, 3.0),
+        ]);
+        assert!(u.contains_point(&Vec2::from_xy(0.5, 2.0)) && !u.contains_point(&Vec2::from_xy(1.5, 2.0)));
+        assert!(!u.contains_point(&Vec2::from_xy(4.0, 0.5)));
+        assert!(!u.is_convex() && square().is_convex());
+        let star: Vec<Vec2> = (0..5).map(|i| Vec2::from_angle(i as f32 * 4.0 * std::f32::consts::PI / 5.0)).collect();
+        let star = Polygon::new(star);
+        assert!(!star.is_convex());
+        // the centre of a pentagram winds twice
+        assert_eq!(star.winding_number(&Vec2::new()), 2);
+    }
+    #[test]
+    fn shape2_polygon_clip() {
+        let triangle = Polygon::new(vec![Vec2::from_xy(-1.0, 1.0), Vec2::from_xy(3.0, 1.0), Vec2::from_xy(1.0, 5.0)]);
+        let clipped = triangle.clip(&square());
+        assert_vec_approx_eq!(clipped.area(), 2.0);
+        assert!(clipped.is_convex());
+        let mut clockwise = square();
+        clockwise.vertices.reverse();
+        assert_vec_approx_eq!(triangle.clip(&clockwise).area(), 2.0);
+        let outside = Polygon::new(vec![Vec2::splat(5.0), Vec2::from_xy(6.0, 5.0), Vec2::splat(6.0)]);
+        assert!(outside.clip(&square()).vertices.is_empty());
+        // a corner of a diamond poking into the square
+        let diamond = Polygon::new(vec![Vec2::from_xy(3.0, 0.0), Vec2::from_xy(6.0, 3.0), Vec2::from_xy(3.0, 6.0), Vec2::from_xy(0.0, 3.0)]);
+        assert_vec_approx_eq!(diamond.clip(&square()).area(), 0.5);
+        let half = square().clip_half_plane(&Vec2::splat(1.0), &Vec2::from_xy(1.0, 1.0));
+        assert_eq!((half.area(), half.vertices.len()), (2.0, 3));
+    }
+}
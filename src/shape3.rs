@@ -0,0 +1,566 @@
+use crate::{Float, MathError, VecN};
+use crate::scalar::clamp_unit;
+
+// 3d primitives, generic over the float type like Quaternion, with f and d suffixed aliases for f32 and f64
+
+pub type Ray3f = Ray3<f32>;
+pub type Ray3d = Ray3<f64>;
+pub type Planef = Plane<f32>;
+pub type Planed = Plane<f64>;
+pub type Spheref = Sphere<f32>;
+pub type Sphered = Sphere<f64>;
+pub type Aabb3f = Aabb3<f32>;
+pub type Aabb3d = Aabb3<f64>;
+pub type Triangle3f = Triangle3<f32>;
+pub type Triangle3d = Triangle3<f64>;
+pub type Segment3f = Segment3<f32>;
+pub type Segment3d = Segment3<f64>;
+pub type Capsulef = Capsule<f32>;
+pub type Capsuled = Capsule<f64>;
+
+// the direction doesn't have to be unit length, hit distances are in multiples of it
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Ray3<T> {
+    pub origin: VecN<T, 3>,
+    pub direction: VecN<T, 3>,
+}
+
+// the points p with normal.dot(p) == distance, normal is unit length
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Plane<T> {
+    pub normal: VecN<T, 3>,
+    pub distance: T,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Sphere<T> {
+    pub center: VecN<T, 3>,
+    pub radius: T,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Aabb3<T> {
+    pub min: VecN<T, 3>,
+    pub max: VecN<T, 3>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Triangle3<T> {
+    pub a: VecN<T, 3>,
+    pub b: VecN<T, 3>,
+    pub c: VecN<T, 3>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Segment3<T> {
+    pub start: VecN<T, 3>,
+    pub end: VecN<T, 3>,
+}
+
+// every point within radius of the segment
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Capsule<T> {
+    pub segment: Segment3<T>,
+    pub radius: T,
+}
+
+impl<T: Float> Ray3<T> {
+    pub fn new(origin: VecN<T, 3>, direction: VecN<T, 3>) -> Self {
+        Ray3 { origin, direction }
+    }
+    pub fn at(&self, t: T) -> VecN<T, 3> {
+        self.origin + self.direction * t
+    }
+    // all intersections return the smallest t >= 0, so a ray starting inside a solid hits at 0
+    pub fn intersect_plane(&self, plane: &Plane<T>) -> Option<T> {
+        let denom = plane.normal.dot(&self.direction);
+        // parallel, either never hits or lies in the plane. anything else has a finite hit,
+        // however short the direction is
+        if denom == T::ZERO {
+            return None;
+        }
+        let t = (plane.distance - plane.normal.dot(&self.origin)) / denom;
+        if t >= T::ZERO { Some(t) } else { None }
+    }
+    pub fn intersect_sphere(&self, sphere: &Sphere<T>) -> Option<T> {
+        let offset = self.origin - sphere.center;
+        let a = self.direction.magnitude_squared();
+        let b = offset.dot(&self.direction);
+        let c = offset.magnitude_squared() - sphere.radius * sphere.radius;
+        // outside and pointing away
+        if c > T::ZERO && b > T::ZERO {
+            return None;
+        }
+        let discriminant = b * b - a * c;
+        if discriminant < T::ZERO || a == T::ZERO {
+            return None;
+        }
+        let t = (-b - discriminant.sqrt()) / a;
+        Some(if t < T::ZERO { T::ZERO } else { t })
+    }
+    // slab test, returns the entry and exit distances. a zero direction component divides to
+    // ±infinity, which keeps a ray parallel to a slab only if it starts between its sides, and
+    // to NaN when it starts on a side, which the comparisons below skip
+    pub fn intersect_aabb(&self, aabb: &Aabb3<T>) -> Option<(T, T)> {
+        let (mut near, mut far) = (T::ZERO, T::INFINITY);
+        for axis in 0..3 {
+            let (origin, direction) = (self.origin.0[axis], self.direction.0[axis]);
+            let (min, max) = (aabb.min.0[axis], aabb.max.0[axis]);
+            let (mut t1, mut t2) = ((min - origin) / direction, (max - origin) / direction);
+            if t1 > t2 {
+                std::mem::swap(&mut t1, &mut t2);
+            }
+            if t1 > near { near = t1; }
+            if t2 < far { far = t2; }
+            if near > far {
+                return None;
+            }
+        }
+        Some((near, far))
+    }
+    // moller-trumbore, hits either side of the triangle
+    pub fn intersect_triangle(&self, triangle: &Triangle3<T>) -> Option<T> {
+        let (edge1, edge2) = (triangle.b - triangle.a, triangle.c - triangle.a);
+        let p = self.direction.cross(&edge2);
+        let det = edge1.dot(&p);
+        // det is the triple product of the direction and edges, so compare it relative to their
+        // lengths. below that the ray is parallel to the plane or the triangle is degenerate
+        let scale = edge1.magnitude() * edge2.magnitude() * self.direction.magnitude();
+        if det.abs() <= T::EPSILON * scale {
+            return None;
+        }
+        let inv_det = T::ONE / det;
+        let s = self.origin - triangle.a;
+        let u = s.dot(&p) * inv_det;
+        if u < T::ZERO || u > T::ONE {
+            return None;
+        }
+        let q = s.cross(&edge1);
+        let v = self.direction.dot(&q) * inv_det;
+        if v < T::ZERO || u + v > T::ONE {
+            return None;
+        }
+        let t = edge2.dot(&q) * inv_det;
+        if t >= T::ZERO { Some(t) } else { None }
+    }
+    // the capsule is the union of the swept cylinder and the spheres at its ends, so the first
+    // hit is the earliest of the hits on each part
+    pub fn intersect_capsule(&self, capsule: &Capsule<T>) -> Option<T> {
+        if capsule.contains_point(&self.origin) {
+            return Some(T::ZERO);
+        }
+        let start = self.intersect_sphere(&Sphere::new(capsule.segment.start, capsule.radius));
+        let end = self.intersect_sphere(&Sphere::new(capsule.segment.end, capsule.radius));
+        // the side of the infinite cylinder, |offset x axis|^2 = radius^2 |axis|^2 scaled through by |axis|^2
+        let axis = capsule.segment.end - capsule.segment.start;
+        let offset = self.origin - capsule.segment.start;
+        let (axis_squared, axis_direction, axis_offset) = (axis.magnitude_squared(), axis.dot(&self.direction), axis.dot(&offset));
+        let a = axis_squared * self.direction.magnitude_squared() - axis_direction * axis_direction;
+        let b = axis_squared * offset.dot(&self.direction) - axis_offset * axis_direction;
+        let c = axis_squared * offset.magnitude_squared() - axis_offset * axis_offset - capsule.radius * capsule.radius * axis_squared;
+        let discriminant = b * b - a * c;
+        let mut side = None;
+        // a == 0 when the ray runs along the axis, or the capsule is a sphere, and the spheres catch those
+        if a != T::ZERO && discriminant >= T::ZERO {
+            let t = (-b - discriminant.sqrt()) / a;
+            let along = axis_offset + t * axis_direction;
+            if t >= T::ZERO && along >= T::ZERO && along <= axis_squared {
+                side = Some(t);
+            }
+        }
+        [start, end, side].into_iter().flatten().reduce(|a, b| if b < a { b } else { a })
+    }
+    // the distance t >= 0 along the ray and the point on the segment where the two come closest,
+    // ericson 5.1.9 with the ray parameter only clamped from below
+    pub fn closest_to_segment(&self, segment: &Segment3<T>) -> (T, VecN<T, 3>) {
+        let (d1, d2) = (self.direction, segment.end - segment.start);
+        let r = self.origin - segment.start;
+        let (a, e, f) = (d1.magnitude_squared(), d2.magnitude_squared(), d2.dot(&r));
+        let positive = |t: T| if t > T::ZERO { t } else { T::ZERO };
+        if a == T::ZERO {
+            return (T::ZERO, segment.closest_point(&self.origin));
+        }
+        let c = d1.dot(&r);
+        if e == T::ZERO {
+            return (positive(-c / a), segment.start);
+        }
+        let b = d1.dot(&d2);
+        let denom = a * e - b * b;
+        // parallel, every point of the overlap is as close so start from the ray origin
+        let mut t = if denom != T::ZERO { positive((b * f - c * e) / denom) } else { T::ZERO };
+        let mut s = (b * t + f) / e;
+        if s < T::ZERO {
+            s = T::ZERO;
+            t = positive(-c / a);
+        } else if s > T::ONE {
+            s = T::ONE;
+            t = positive((b - c) / a);
+        }
+        (t, segment.start + d2 * s)
+    }
+    pub fn distance_to_segment(&self, segment: &Segment3<T>) -> T {
+        let (t, point) = self.closest_to_segment(segment);
+        self.at(t).distance(&point)
+    }
+}
+
+impl<T: Float> Plane<T> {
+    pub fn new(normal: VecN<T, 3>, distance: T) -> Result<Self, MathError> {
+        // scale the distance with the normal so the plane itself doesn't move
+        let magnitude = normal.magnitude();
+        Ok(Plane { normal: normal.try_normalise()?, distance: distance / magnitude })
+    }
+    pub fn from_point_normal(point: VecN<T, 3>, normal: VecN<T, 3>) -> Result<Self, MathError> {
+        let normal = normal.try_normalise()?;
+        Ok(Plane { normal, distance: normal.dot(&point) })
+    }
+    // counter-clockwise points face the normal, collinear points are a zero length error
+    pub fn from_points(a: VecN<T, 3>, b: VecN<T, 3>, c: VecN<T, 3>) -> Result<Self, MathError> {
+        Self::from_point_normal(a, (b - a).cross(&(c - a)))
+    }
+    // positive on the side the normal points to
+    pub fn signed_distance(&self, point: &VecN<T, 3>) -> T {
+        self.normal.dot(point) - self.distance
+    }
+    pub fn closest_point(&self, point: &VecN<T, 3>) -> VecN<T, 3> {
+        *point - self.normal * self.signed_distance(point)
+    }
+    pub fn overlaps_sphere(&self, sphere: &Sphere<T>) -> bool {
+        self.signed_distance(&sphere.center).abs() <= sphere.radius
+    }
+    // the box projected onto the normal reaches half_extents . |normal| either side of its center
+    pub fn overlaps_aabb(&self, aabb: &Aabb3<T>) -> bool {
+        self.signed_distance(&aabb.center()).abs() <= aabb.half_extents().dot(&self.normal.abs())
+    }
+}
+
+impl<T: Float> Sphere<T> {
+    pub fn new(center: VecN<T, 3>, radius: T) -> Self {
+        Sphere { center, radius }
+    }
+    pub fn contains_point(&self, point: &VecN<T, 3>) -> bool {
+        self.center.distance_squared(point) <= self.radius * self.radius
+    }
+    // points inside are their own closest point, otherwise the nearest point on the surface
+    pub fn closest_point(&self, point: &VecN<T, 3>) -> VecN<T, 3> {
+        if self.contains_point(point) {
+            return *point;
+        }
+        self.center + (*point - self.center).with_length(self.radius)
+    }
+    pub fn overlaps_sphere(&self, other: &Self) -> bool {
+        let radius = self.radius + other.radius;
+        self.center.distance_squared(&other.center) <= radius * radius
+    }
+    pub fn overlaps_aabb(&self, aabb: &Aabb3<T>) -> bool {
+        self.contains_point(&aabb.closest_point(&self.center))
+    }
+    pub fn overlaps_triangle(&self, triangle: &Triangle3<T>) -> bool {
+        self.contains_point(&triangle.closest_point(&self.center))
+    }
+}
+
+impl<T: Float> Aabb3<T> {
+    // the corners can be given in any order
+    pub fn new(a: VecN<T, 3>, b: VecN<T, 3>) -> Self {
+        Aabb3 { min: a.min(&b), max: a.max(&b) }
+    }
+    pub fn from_points(points: &[VecN<T, 3>]) -> Option<Self> {
+        let first = *points.first()?;
+        Some(points.iter().fold(Aabb3 { min: first, max: first }, |aabb, point| {
+            Aabb3 { min: aabb.min.min(point), max: aabb.max.max(point) }
+        }))
+    }
+    pub fn center(&self) -> VecN<T, 3> {
+        (self.min + self.max) / (T::ONE + T::ONE)
+    }
+    pub fn half_extents(&self) -> VecN<T, 3> {
+        (self.max - self.min) / (T::ONE + T::ONE)
+    }
+    pub fn contains_point(&self, point: &VecN<T, 3>) -> bool {
+        (0..3).all(|i| point.0[i] >= self.min.0[i] && point.0[i] <= self.max.0[i])
+    }
+    pub fn closest_point(&self, point: &VecN<T, 3>) -> VecN<T, 3> {
+        point.clamp(&self.min, &self.max)
+    }
+    pub fn overlaps_aabb(&self, other: &Self) -> bool {
+        (0..3).all(|i| self.min.0[i] <= other.max.0[i] && other.min.0[i] <= self.max.0[i])
+    }
+    // separating axis test from ericson, real-time collision detection 5.2.9. the candidates are the
+    // box faces, the triangle normal and each box axis crossed with each triangle edge. a zero axis
+    // from a parallel edge or a degenerate triangle projects everything to zero and never separates
+    pub fn overlaps_triangle(&self, triangle: &Triangle3<T>) -> bool {
+        let (center, extents) = (self.center(), self.half_extents());
+        let vertices = [triangle.a - center, triangle.b - center, triangle.c - center];
+        let edges = [vertices[1] - vertices[0], vertices[2] - vertices[1], vertices[0] - vertices[2]];
+        let box_axes = (0..3).map(VecN::<T, 3>::axis);
+        let cross_axes = edges.into_iter().flat_map(|edge| (0..3).map(move |i| VecN::<T, 3>::axis(i).cross(&edge)));
+        let mut axes = box_axes.chain(std::iter::once(edges[0].cross(&edges[1]))).chain(cross_axes);
+        axes.all(|axis| {
+            let radius = extents.dot(&axis.abs());
+            let projected = VecN(vertices.map(|vertex| vertex.dot(&axis)));
+            projected.min_element() <= radius && projected.max_element() >= -radius
+        })
+    }
+}
+
+impl<T: Float> Triangle3<T> {
+    pub fn new(a: VecN<T, 3>, b: VecN<T, 3>, c: VecN<T, 3>) -> Self {
+        Triangle3 { a, b, c }
+    }
+    // counter-clockwise winding faces the normal, zero for a degenerate triangle
+    pub fn normal(&self) -> VecN<T, 3> {
+        (self.b - self.a).cross(&(self.c - self.a)).normalise()
+    }
+    pub fn area(&self) -> T {
+        (self.b - self.a).cross(&(self.c - self.a)).magnitude() / (T::ONE + T::ONE)
+    }
+    // voronoi region walk from ericson, real-time collision detection 5.1.5
+    pub fn closest_point(&self, point: &VecN<T, 3>) -> VecN<T, 3> {
+        let (a, b, c, p) = (self.a, self.b, self.c, *point);
+        let (ab, ac, ap) = (b - a, c - a, p - a);
+        let (d1, d2) = (ab.dot(&ap), ac.dot(&ap));
+        if d1 <= T::ZERO && d2 <= T::ZERO {
+            return a;
+        }
+        let bp = p - b;
+        let (d3, d4) = (ab.dot(&bp), ac.dot(&bp));
+        if d3 >= T::ZERO && d4 <= d3 {
+            return b;
+        }
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= T::ZERO && d1 >= T::ZERO && d3 <= T::ZERO {
+            return a + ab * (d1 / (d1 - d3));
+        }
+        let cp = p - c;
+        let (d5, d6) = (ab.dot(&cp), ac.dot(&cp));
+        if d6 >= T::ZERO && d5 <= d6 {
+            return c;
+        }
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= T::ZERO && d2 >= T::ZERO && d6 <= T::ZERO {
+            return a + ac * (d2 / (d2 - d6));
+        }
+        let va = d3 * d6 - d5 * d4;
+        if va <= T::ZERO && d4 - d3 >= T::ZERO && d5 - d6 >= T::ZERO {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+        let denom = T::ONE / (va + vb + vc);
+        a + ab * (vb * denom) + ac * (vc * denom)
+    }
+}
+
+impl<T: Float> Segment3<T> {
+    pub fn new(start: VecN<T, 3>, end: VecN<T, 3>) -> Self {
+        Segment3 { start, end }
+    }
+    pub fn length(&self) -> T {
+        self.start.distance(&self.end)
+    }
+    pub fn closest_point(&self, point: &VecN<T, 3>) -> VecN<T, 3> {
+        let direction = self.end - self.start;
+        let length_squared = direction.magnitude_squared();
+        if length_squared == T::ZERO {
+            return self.start;
+        }
+        self.start + direction * clamp_unit((*point - self.start).dot(&direction) / length_squared)
+    }
+    pub fn distance_to_point(&self, point: &VecN<T, 3>) -> T {
+        self.closest_point(point).distance(point)
+    }
+    // the closest pair of points, one on each segment, from ericson 5.1.9
+    pub fn closest_points(&self, other: &Self) -> (VecN<T, 3>, VecN<T, 3>) {
+        let (d1, d2) = (self.end - self.start, other.end - other.start);
+        let r = self.start - other.start;
+        let (a, e, f) = (d1.magnitude_squared(), d2.magnitude_squared(), d2.dot(&r));
+        // either or both segments degenerate to a point, anything longer is divided by safely
+        if a == T::ZERO && e == T::ZERO {
+            return (self.start, other.start);
+        }
+        if a == T::ZERO {
+            return (self.start, other.closest_point(&self.start));
+        }
+        let c = d1.dot(&r);
+        if e == T::ZERO {
+            return (self.closest_point(&other.start), other.start);
+        }
+        let b = d1.dot(&d2);
+        let denom = a * e - b * b;
+        // parallel segments have no unique pair, so start from an arbitrary point on self
+        let mut s = if denom != T::ZERO { clamp_unit((b * f - c * e) / denom) } else { T::ZERO };
+        let mut t = (b * s + f) / e;
+        if t < T::ZERO {
+            t = T::ZERO;
+            s = clamp_unit(-c / a);
+        } else if t > T::ONE {
+            t = T::ONE;
+            s = clamp_unit((b - c) / a);
+        }
+        (self.start + d1 * s, other.start + d2 * t)
+    }
+    pub fn distance_to_segment(&self, other: &Self) -> T {
+        let (p, q) = self.closest_points(other);
+        p.distance(&q)
+    }
+}
+
+impl<T: Float> Capsule<T> {
+    pub fn new(start: VecN<T, 3>, end: VecN<T, 3>, radius: T) -> Self {
+        Capsule { segment: Segment3::new(start, end), radius }
+    }
+    pub fn contains_point(&self, point: &VecN<T, 3>) -> bool {
+        self.segment.closest_point(point).distance_squared(point) <= self.radius * self.radius
+    }
+    pub fn closest_point(&self, point: &VecN<T, 3>) -> VecN<T, 3> {
+        let center = self.segment.closest_point(point);
+        Sphere::new(center, self.radius).closest_point(point)
+    }
+    pub fn overlaps_sphere(&self, sphere: &Sphere<T>) -> bool {
+        let center = self.segment.closest_point(&sphere.center);
+        Sphere::new(center, self.radius).overlaps_sphere(sphere)
+    }
+    pub fn overlaps_capsule(&self, other: &Self) -> bool {
+        let radius = self.radius + other.radius;
+        self.segment.distance_to_segment(&other.segment) <= radius
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_vec_approx_eq, DVec3, Vec3};
+
+    #[test]
+    fn shape3_ray_plane_sphere() {
+        let ray = Ray3::new(Vec3::from_xyz(0.0, 0.0, -5.0), Vec3::z_axis());
+        let plane = Plane::from_point_normal(Vec3::from_xyz(0.0, 0.0, 2.0), -Vec3::z_axis()).unwrap();
+        assert_eq!(ray.intersect_plane(&plane), Some(7.0));
+        assert_eq!(Ray3::new(Vec3::new(), Vec3::x_axis()).intersect_plane(&plane), None);
+        let sphere = Sphere::new(Vec3::new(), 1.0);
+        assert_eq!(ray.intersect_sphere(&sphere), Some(4.0));
+        assert_eq!(Ray3::new(Vec3::new(), Vec3::x_axis()).intersect_sphere(&sphere), Some(0.0));
+        assert_eq!(Ray3::new(Vec3::from_xyz(0.0, 2.0, -5.0), Vec3::z_axis()).intersect_sphere(&sphere), None);
+        assert_eq!(Ray3::new(Vec3::from_xyz(0.0, 0.0, 5.0), Vec3::z_axis()).intersect_sphere(&sphere), None);
+        assert_eq!(Plane::new(Vec3::from_xyz(0.0, 2.0, 0.0), 4.0), Ok(Plane { normal: Vec3::y_axis(), distance: 2.0 }));
+        assert_eq!(Plane::from_points(Vec3::new(), Vec3::x_axis(), Vec3::x_axis() * 2.0), Err(MathError::ZeroLength));
+        assert_eq!(plane.signed_distance(&Vec3::new()), 2.0);
+    }
+    #[test]
+    fn shape3_ray_aabb() {
+        let aabb = Aabb3::new(Vec3::splat(1.0), Vec3::splat(-1.0));
+        let ray = Ray3::new(Vec3::from_xyz(-3.0, 0.5, 0.0), Vec3::x_axis());
+        assert_eq!(ray.intersect_aabb(&aabb), Some((2.0, 4.0)));
+        assert_eq!(Ray3::new(Vec3::new(), Vec3::y_axis()).intersect_aabb(&aabb), Some((0.0, 1.0)));
+        assert_eq!(Ray3::new(Vec3::from_xyz(-3.0, 2.0, 0.0), Vec3::x_axis()).intersect_aabb(&aabb), None);
+        assert_eq!(Ray3::new(Vec3::from_xyz(-3.0, -3.0, 0.0), Vec3::from_xyz(1.0, 3.0, 0.0)).intersect_aabb(&aabb), None);
+        assert_eq!(Ray3::new(Vec3::from_xyz(3.0, 0.0, 0.0), Vec3::x_axis()).intersect_aabb(&aabb), None);
+        // grazing a side while parallel to it
+        assert_eq!(Ray3::new(Vec3::from_xyz(-3.0, 1.0, 0.0), Vec3::x_axis()).intersect_aabb(&aabb), Some((2.0, 4.0)));
+    }
+    #[test]
+    fn shape3_ray_tiny() {
+        // nothing is scaled against a fixed epsilon, so tiny directions and shapes still hit
+        let aabb = Aabb3::new(Vec3::splat(1.0), Vec3::splat(-1.0));
+        let ray = Ray3::new(Vec3::from_xyz(-3.0, 0.5, 0.0), Vec3::x_axis() * 1e-8);
+        let (near, far) = ray.intersect_aabb(&aabb).unwrap();
+        assert_vec_approx_eq!(near, 2e8);
+        assert_vec_approx_eq!(far, 4e8);
+        let plane = Plane::from_point_normal(Vec3::x_axis(), Vec3::x_axis()).unwrap();
+        assert_vec_approx_eq!(ray.intersect_plane(&plane).unwrap(), 4e8);
+        let triangle = Triangle3::new(Vec3::new(), Vec3::from_xyz(0.0, 1.0, 0.0), Vec3::from_xyz(0.0, 0.0, 1.0));
+        assert_vec_approx_eq!(Ray3::new(Vec3::from_xyz(-1.0, 0.25, 0.25), Vec3::x_axis() * 1e-8).intersect_triangle(&triangle).unwrap(), 1e8);
+        let small = Triangle3::new(Vec3::new(), Vec3::from_xyz(0.0, 1e-4, 0.0), Vec3::from_xyz(0.0, 0.0, 1e-4));
+        assert_vec_approx_eq!(Ray3::new(Vec3::from_xyz(-1.0, 2e-5, 2e-5), Vec3::x_axis()).intersect_triangle(&small).unwrap(), 1.0);
+        let short = Segment3::new(Vec3::new(), Vec3::from_xyz(1e-3, 0.0, 0.0));
+        assert_eq!(short.closest_points(&Segment3::new(Vec3::from_xyz(5e-4, 1.0, 0.0), Vec3::from_xyz(5e-4, 2.0, 0.0))).0, Vec3::from_xyz(5e-4, 0.0, 0.0));
+    }
+    #[test]
+    fn shape3_ray_capsule_segment() {
+        let capsule = Capsule::new(Vec3::from_xyz(0.0, -2.0, 0.0), Vec3::from_xyz(0.0, 2.0, 0.0), 0.5);
+        // the side, the end caps, from inside and a miss
+        assert_eq!(Ray3::new(Vec3::from_xyz(-3.0, 1.0, 0.0), Vec3::x_axis()).intersect_capsule(&capsule), Some(2.5));
+        assert_eq!(Ray3::new(Vec3::from_xyz(0.0, 5.0, 0.0), -Vec3::y_axis() * 2.0).intersect_capsule(&capsule), Some(1.25));
+        assert_vec_approx_eq!(Ray3::new(Vec3::from_xyz(-3.0, 2.3, 0.0), Vec3::x_axis()).intersect_capsule(&capsule).unwrap(), 2.6);
+        assert_eq!(Ray3::new(Vec3::new(), Vec3::x_axis()).intersect_capsule(&capsule), Some(0.0));
+        assert_eq!(Ray3::new(Vec3::from_xyz(-3.0, 2.6, 0.0), Vec3::x_axis()).intersect_capsule(&capsule), None);
+        assert_eq!(Ray3::new(Vec3::from_xyz(-3.0, 0.0, 0.0), -Vec3::x_axis()).intersect_capsule(&capsule), None);
+        let segment = Segment3::new(Vec3::from_xyz(2.0, -1.0, 1.0), Vec3::from_xyz(2.0, 1.0, 1.0));
+        let ray = Ray3::new(Vec3::new(), Vec3::x_axis() * 2.0);
+        assert_eq!(ray.closest_to_segment(&segment), (1.0, Vec3::from_xyz(2.0, 0.0, 1.0)));
+        assert_eq!(ray.distance_to_segment(&segment), 1.0);
+        // behind the origin, so the ray's closest point is its start
+        assert_eq!(Ray3::new(Vec3::from_xyz(3.0, 0.0, 0.0), Vec3::x_axis()).closest_to_segment(&segment), (0.0, Vec3::from_xyz(2.0, 0.0, 1.0)));
+        let parallel = Segment3::new(Vec3::from_xyz(-4.0, 1.0, 0.0), Vec3::from_xyz(-2.0, 1.0, 0.0));
+        assert_eq!(ray.closest_to_segment(&parallel), (0.0, Vec3::from_xyz(-2.0, 1.0, 0.0)));
+        assert_eq!(Ray3d::new(DVec3::new(), DVec3::x_axis()).distance_to_segment(&Segment3d::new(DVec3::y_axis(), DVec3::splat(1.0))), 1.0);
+    }
+    #[test]
+    fn shape3_ray_triangle() {
+        let triangle = Triangle3::new(Vec3::new(), Vec3::x_axis(), Vec3::y_axis());
+        let down = -Vec3::z_axis();
+        assert_eq!(Ray3::new(Vec3::from_xyz(0.25, 0.25, 3.0), down).intersect_triangle(&triangle), Some(3.0));
+        assert_eq!(Ray3::new(Vec3::from_xyz(0.75, 0.75, 3.0), down).intersect_triangle(&triangle), None);
+        assert_eq!(Ray3::new(Vec3::from_xyz(0.25, 0.25, -3.0), down).intersect_triangle(&triangle), None);
+        assert_eq!(triangle.normal(), Vec3::z_axis());
+        assert_eq!(triangle.area(), 0.5);
+    }
+    #[test]
+    fn shape3_closest_points() {
+        let triangle = Triangle3::new(Vec3::new(), Vec3::from_xyz(2.0, 0.0, 0.0), Vec3::from_xyz(0.0, 2.0, 0.0));
+        assert_eq!(triangle.closest_point(&Vec3::from_xyz(0.5, 0.5, 4.0)), Vec3::from_xyz(0.5, 0.5, 0.0));
+        assert_eq!(triangle.closest_point(&Vec3::from_xyz(-1.0, -1.0, 0.0)), Vec3::new());
+        assert_eq!(triangle.closest_point(&Vec3::from_xyz(1.0, -1.0, 1.0)), Vec3::from_xyz(1.0, 0.0, 0.0));
+        assert_vec_approx_eq!(triangle.closest_point(&Vec3::from_xyz(2.0, 2.0, 0.0)), Vec3::from_xyz(1.0, 1.0, 0.0));
+        let segment = Segment3::new(Vec3::new(), Vec3::from_xyz(4.0, 0.0, 0.0));
+        assert_eq!(segment.closest_point(&Vec3::from_xyz(1.0, 3.0, 0.0)), Vec3::x_axis());
+        assert_eq!(segment.closest_point(&Vec3::from_xyz(-1.0, 3.0, 0.0)), Vec3::new());
+        let crossing = Segment3::new(Vec3::from_xyz(2.0, -1.0, 1.0), Vec3::from_xyz(2.0, 1.0, 1.0));
+        assert_eq!(segment.closest_points(&crossing), (Vec3::from_xyz(2.0, 0.0, 0.0), Vec3::from_xyz(2.0, 0.0, 1.0)));
+        let parallel = Segment3::new(Vec3::from_xyz(5.0, 1.0, 0.0), Vec3::from_xyz(6.0, 1.0, 0.0));
+        assert_eq!(segment.distance_to_segment(&parallel), 2.0_f32.sqrt());
+        assert_eq!(Aabb3::new(Vec3::new(), Vec3::splat(1.0)).closest_point(&Vec3::from_xyz(2.0, 0.5, -1.0)), Vec3::from_xyz(1.0, 0.5, 0.0));
+        assert_eq!(Sphere::new(Vec3::new(), 2.0).closest_point(&Vec3::from_xyz(0.0, 4.0, 0.0)), Vec3::from_xyz(0.0, 2.0, 0.0));
+    }
+    #[test]
+    fn shape3_overlaps() {
+        let sphere = Sphere::new(Vec3::new(), 1.0);
+        assert!(sphere.overlaps_sphere(&Sphere::new(Vec3::from_xyz(2.0, 0.0, 0.0), 1.0)));
+        assert!(!sphere.overlaps_sphere(&Sphere::new(Vec3::from_xyz(2.1, 0.0, 0.0), 1.0)));
+        let aabb = Aabb3::new(Vec3::splat(1.0), Vec3::splat(2.0));
+        assert!(!sphere.overlaps_aabb(&aabb));
+        assert!(Sphere::new(Vec3::new(), 1.8).overlaps_aabb(&aabb));
+        assert!(aabb.overlaps_aabb(&Aabb3::new(Vec3::splat(2.0), Vec3::splat(3.0))));
+        assert!(!aabb.overlaps_aabb(&Aabb3::new(Vec3::from_xyz(2.5, 1.0, 1.0), Vec3::splat(3.0))));
+        assert_eq!(Aabb3::from_points(&[Vec3::x_axis(), -Vec3::y_axis(), Vec3::z_axis()]), Some(Aabb3::new(Vec3::from_xyz(0.0, -1.0, 0.0), Vec3::from_xyz(1.0, 0.0, 1.0))));
+        let triangle = Triangle3::new(Vec3::from_xyz(-5.0, -5.0, 0.5), Vec3::from_xyz(5.0, -5.0, 0.5), Vec3::from_xyz(0.0, 5.0, 0.5));
+        assert!(sphere.overlaps_triangle(&triangle));
+        let capsule = Capsule::new(Vec3::from_xyz(0.0, -2.0, 0.0), Vec3::from_xyz(0.0, 2.0, 0.0), 0.5);
+        assert!(capsule.contains_point(&Vec3::from_xyz(0.4, 2.0, 0.0)) && !capsule.contains_point(&Vec3::from_xyz(0.0, 2.6, 0.0)));
+        assert!(capsule.overlaps_sphere(&Sphere::new(Vec3::from_xyz(1.4, 1.0, 0.0), 1.0)));
+        assert!(capsule.overlaps_capsule(&Capsule::new(Vec3::from_xyz(-2.0, 0.0, 0.9), Vec3::from_xyz(2.0, 0.0, 0.9), 0.5)));
+        assert!(!capsule.overlaps_capsule(&Capsule::new(Vec3::from_xyz(-2.0, 0.0, 1.1), Vec3::from_xyz(2.0, 0.0, 1.1), 0.5)));
+        assert!(Plane::from_point_normal(Vec3::from_xyz(0.0, 0.9, 0.0), Vec3::y_axis()).unwrap().overlaps_sphere(&sphere));
+    }
+    #[test]
+    fn shape3_aabb_plane_triangle() {
+        let aabb = Aabb3::new(Vec3::new(), Vec3::splat(1.0));
+        let diagonal = Vec3::splat(1.0).normalise();
+        // the far corner sits at distance sqrt(3) along the diagonal
+        assert!(Plane::new(diagonal, 1.7).unwrap().overlaps_aabb(&aabb));
+        assert!(!Plane::new(diagonal, 1.8).unwrap().overlaps_aabb(&aabb));
+        assert!(!Plane::new(-diagonal, 0.1).unwrap().overlaps_aabb(&aabb));
+        assert!(Plane::new(Vec3::z_axis(), 1.0).unwrap().overlaps_aabb(&aabb));
+        let through = Triangle3::new(Vec3::from_xyz(-5.0, -5.0, 0.5), Vec3::from_xyz(5.0, -5.0, 0.5), Vec3::from_xyz(0.0, 5.0, 0.5));
+        assert!(aabb.overlaps_triangle(&through));
+        assert!(aabb.overlaps_triangle(&Triangle3::new(Vec3::splat(0.2), Vec3::splat(0.4), Vec3::from_xyz(0.3, 0.2, 0.6))));
+        // touching a corner
+        assert!(aabb.overlaps_triangle(&Triangle3::new(Vec3::splat(1.0), Vec3::from_xyz(2.0, 1.0, 1.0), Vec3::from_xyz(1.0, 2.0, 1.0))));
+        // separated by a box face and by the triangle plane
+        assert!(!aabb.overlaps_triangle(&Triangle3::new(Vec3::from_xyz(1.5, -5.0, -5.0), Vec3::from_xyz(1.5, 5.0, -5.0), Vec3::from_xyz(1.5, 0.0, 5.0))));
+        assert!(!aabb.overlaps_triangle(&Triangle3::new(Vec3::from_xyz(2.5, 0.5, 0.5), Vec3::from_xyz(0.5, 2.5, 0.5), Vec3::from_xyz(0.5, 0.5, 2.5))));
+        // only an edge cross axis separates these, the faces and the normal all overlap
+        let edge_separated = Triangle3::new(Vec3::from_xyz(-0.5, 0.0, 0.0), Vec3::from_xyz(-1.0, -0.5, -0.5), Vec3::from_xyz(0.0, 1.0, -0.5));
+        assert!(!aabb.overlaps_triangle(&edge_separated));
+        assert!(!Aabb3d::new(DVec3::new(), DVec3::splat(1.0)).overlaps_triangle(&Triangle3d::new(DVec3::from_xyz(-0.5, 0.0, 0.0), DVec3::from_xyz(-1.0, -0.5, -0.5), DVec3::from_xyz(0.0, 1.0, -0.5))));
+    }
+}
@@ -0,0 +1,117 @@
+// four lane f32 operations backing Vec3A and Vec4A, sse2/neon with the `simd` feature and plain loops otherwise
+
+#[cfg(all(feature = "simd", any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse2"))))]
+mod backend {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    #[inline]
+    fn load(lanes: [f32; 4]) -> __m128 {
+        // safety: sse2 is enabled for this target and the pointer is to 4 valid floats
+        unsafe { _mm_loadu_ps(lanes.as_ptr()) }
+    }
+    #[inline]
+    fn store(reg: __m128) -> [f32; 4] {
+        let mut lanes = [0.0; 4];
+        // safety: as above, writes exactly 4 floats
+        unsafe { _mm_storeu_ps(lanes.as_mut_ptr(), reg) };
+        lanes
+    }
+    #[inline]
+    pub fn add(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        store(unsafe { _mm_add_ps(load(a), load(b)) })
+    }
+    #[inline]
+    pub fn sub(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        store(unsafe { _mm_sub_ps(load(a), load(b)) })
+    }
+    #[inline]
+    pub fn mul(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        store(unsafe { _mm_mul_ps(load(a), load(b)) })
+    }
+    #[inline]
+    pub fn div(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        store(unsafe { _mm_div_ps(load(a), load(b)) })
+    }
+    #[inline]
+    pub fn dot(a: [f32; 4], b: [f32; 4]) -> f32 {
+        unsafe {
+            let prod = _mm_mul_ps(load(a), load(b));
+            // (x+y, x+y, z+w, z+w) then add the high pair onto the low one
+            let swapped = _mm_shuffle_ps(prod, prod, 0b10_11_00_01);
+            let pairs = _mm_add_ps(prod, swapped);
+            let high = _mm_movehl_ps(swapped, pairs);
+            _mm_cvtss_f32(_mm_add_ss(pairs, high))
+        }
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "aarch64", target_feature = "neon"))]
+mod backend {
+    use std::arch::aarch64::*;
+
+    #[inline]
+    fn load(lanes: [f32; 4]) -> float32x4_t {
+        // safety: neon is enabled for this target and the pointer is to 4 valid floats
+        unsafe { vld1q_f32(lanes.as_ptr()) }
+    }
+    #[inline]
+    fn store(reg: float32x4_t) -> [f32; 4] {
+        let mut lanes = [0.0; 4];
+        // safety: as above, writes exactly 4 floats
+        unsafe { vst1q_f32(lanes.as_mut_ptr(), reg) };
+        lanes
+    }
+    #[inline]
+    pub fn add(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        store(unsafe { vaddq_f32(load(a), load(b)) })
+    }
+    #[inline]
+    pub fn sub(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        store(unsafe { vsubq_f32(load(a), load(b)) })
+    }
+    #[inline]
+    pub fn mul(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        store(unsafe { vmulq_f32(load(a), load(b)) })
+    }
+    #[inline]
+    pub fn div(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        store(unsafe { vdivq_f32(load(a), load(b)) })
+    }
+    #[inline]
+    pub fn dot(a: [f32; 4], b: [f32; 4]) -> f32 {
+        unsafe { vaddvq_f32(vmulq_f32(load(a), load(b))) }
+    }
+}
+
+#[cfg(not(all(feature = "simd", any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse2"),
+    all(target_arch = "aarch64", target_feature = "neon"),
+))))]
+mod backend {
+    #[inline]
+    pub fn add(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        std::array::from_fn(|i| a[i] + b[i])
+    }
+    #[inline]
+    pub fn sub(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        std::array::from_fn(|i| a[i] - b[i])
+    }
+    #[inline]
+    pub fn mul(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        std::array::from_fn(|i| a[i] * b[i])
+    }
+    #[inline]
+    pub fn div(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+        std::array::from_fn(|i| a[i] / b[i])
+    }
+    #[inline]
+    pub fn dot(a: [f32; 4], b: [f32; 4]) -> f32 {
+        (a[0] * b[0] + a[1] * b[1]) + (a[2] * b[2] + a[3] * b[3])
+    }
+}
+
+pub(crate) use backend::*;
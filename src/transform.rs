@@ -0,0 +1,202 @@
+use crate::{Float, MatN, MathError, VecN};
+
+// 3d affine transforms and projections as 4x4 matrices, angles in radians
+impl<T: Float> MatN<T, 4> {
+    pub fn from_translation(translation: &VecN<T, 3>) -> Self {
+        let mut mat = Self::identity();
+        mat.0[3] = translation.xyz1().0;
+        mat
+    }
+    pub fn from_scale(scale: &VecN<T, 3>) -> Self {
+        Self::from_diagonal(&scale.xyz1())
+    }
+    pub fn from_rotation_x(angle: T) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        MatN([
+            [T::ONE, T::ZERO, T::ZERO, T::ZERO],
+            [T::ZERO, cos, sin, T::ZERO],
+            [T::ZERO, -sin, cos, T::ZERO],
+            [T::ZERO, T::ZERO, T::ZERO, T::ONE],
+        ])
+    }
+    pub fn from_rotation_y(angle: T) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        MatN([
+            [cos, T::ZERO, -sin, T::ZERO],
+            [T::ZERO, T::ONE, T::ZERO, T::ZERO],
+            [sin, T::ZERO, cos, T::ZERO],
+            [T::ZERO, T::ZERO, T::ZERO, T::ONE],
+        ])
+    }
+    pub fn from_rotation_z(angle: T) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        MatN([
+            [cos, sin, T::ZERO, T::ZERO],
+            [-sin, cos, T::ZERO, T::ZERO],
+            [T::ZERO, T::ZERO, T::ONE, T::ZERO],
+            [T::ZERO, T::ZERO, T::ZERO, T::ONE],
+        ])
+    }
+    pub fn from_axis_angle(axis: &VecN<T, 3>, angle: T) -> Self {
+        // rodrigues' rotation formula, the axis does not have to be normalised
+        let [x, y, z] = axis.normalise().0;
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let t = T::ONE - cos;
+        MatN([
+            [x * x * t + cos, x * y * t + z * sin, x * z * t - y * sin, T::ZERO],
+            [x * y * t - z * sin, y * y * t + cos, y * z * t + x * sin, T::ZERO],
+            [x * z * t + y * sin, y * z * t - x * sin, z * z * t + cos, T::ZERO],
+            [T::ZERO, T::ZERO, T::ZERO, T::ONE],
+        ])
+    }
+    // view matrix from the basis (side, up, forward), forward is what ends up along the view z axis
+    fn look_to(eye: &VecN<T, 3>, side: VecN<T, 3>, up: VecN<T, 3>, forward: VecN<T, 3>) -> Self {
+        let mut mat = Self::from_rows([side.xyz0(), up.xyz0(), forward.xyz0(), VecN::w_axis()]);
+        mat.0[3] = [-side.dot(eye), -up.dot(eye), -forward.dot(eye), T::ONE];
+        mat
+    }
+    pub fn look_at_rh(eye: &VecN<T, 3>, center: &VecN<T, 3>, up: &VecN<T, 3>) -> Self {
+        let forward = (center - eye).normalise();
+        let side = forward.cross(up).normalise();
+        let up = side.cross(&forward);
+        Self::look_to(eye, side, up, -forward)
+    }
+    pub fn look_at_lh(eye: &VecN<T, 3>, center: &VecN<T, 3>, up: &VecN<T, 3>) -> Self {
+        let forward = (center - eye).normalise();
+        let side = up.cross(&forward).normalise();
+        let up = forward.cross(&side);
+        Self::look_to(eye, side, up, forward)
+    }
+    // `depth_z`/`depth_w` are the third column's z and the fourth column's z of the projection
+    fn perspective(fov_y: T, aspect: T, handedness: T, depth_z: T, depth_w: T) -> Self {
+        let two = T::ONE + T::ONE;
+        let focal = T::ONE / (fov_y / two).tan();
+        MatN([
+            [focal / aspect, T::ZERO, T::ZERO, T::ZERO],
+            [T::ZERO, focal, T::ZERO, T::ZERO],
+            [T::ZERO, T::ZERO, depth_z, handedness],
+            [T::ZERO, T::ZERO, depth_w, T::ZERO],
+        ])
+    }
+    // opengl clip space, depth in [-1, 1]
+    pub fn perspective_rh_gl(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        let two = T::ONE + T::ONE;
+        Self::perspective(fov_y, aspect, -T::ONE, (far + near) / (near - far), two * far * near / (near - far))
+    }
+    pub fn perspective_lh_gl(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        let two = T::ONE + T::ONE;
+        Self::perspective(fov_y, aspect, T::ONE, (far + near) / (far - near), -two * far * near / (far - near))
+    }
+    // vulkan/directx/metal clip space, depth in [0, 1]
+    pub fn perspective_rh(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        Self::perspective(fov_y, aspect, -T::ONE, far / (near - far), near * far / (near - far))
+    }
+    pub fn perspective_lh(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        Self::perspective(fov_y, aspect, T::ONE, far / (far - near), -near * far / (far - near))
+    }
+    fn orthographic(left: T, right: T, bottom: T, top: T, depth_z: T, depth_w: T) -> Self {
+        let two = T::ONE + T::ONE;
+        MatN([
+            [two / (right - left), T::ZERO, T::ZERO, T::ZERO],
+            [T::ZERO, two / (top - bottom), T::ZERO, T::ZERO],
+            [T::ZERO, T::ZERO, depth_z, T::ZERO],
+            [-(right + left) / (right - left), -(top + bottom) / (top - bottom), depth_w, T::ONE],
+        ])
+    }
+    pub fn orthographic_rh_gl(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let two = T::ONE + T::ONE;
+        Self::orthographic(left, right, bottom, top, -two / (far - near), -(far + near) / (far - near))
+    }
+    pub fn orthographic_lh_gl(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let two = T::ONE + T::ONE;
+        Self::orthographic(left, right, bottom, top, two / (far - near), -(far + near) / (far - near))
+    }
+    pub fn orthographic_rh(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        Self::orthographic(left, right, bottom, top, T::ONE / (near - far), near / (near - far))
+    }
+    pub fn orthographic_lh(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        Self::orthographic(left, right, bottom, top, T::ONE / (far - near), -near / (far - near))
+    }
+    pub fn transform_point3(&self, point: &VecN<T, 3>) -> VecN<T, 3> {
+        let transformed = self * point.xyz1();
+        // perspective divide, a no-op for affine transforms where w stays 1.
+        // a point on the camera plane of a projection has w = 0 and comes out infinite or NaN
+        transformed.xyz() / transformed.w()
+    }
+    pub fn try_transform_point3(&self, point: &VecN<T, 3>) -> Result<VecN<T, 3>, MathError> {
+        (self * point.xyz1()).project_to_vec3()
+    }
+    pub fn transform_vector3(&self, vector: &VecN<T, 3>) -> VecN<T, 3> {
+        // directions have w = 0 so translation does not apply
+        (self * vector.xyz0()).xyz()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_vec_approx_eq, Mat4, MathError, Vec3};
+    use std::f32::consts::FRAC_PI_2;
+
+    #[test]
+    fn transform_translation_scale() {
+        let m = Mat4::from_translation(&Vec3::from_xyz(1.0, 2.0, 3.0)) * Mat4::from_scale(&Vec3::splat(2.0));
+        assert_eq!(m.transform_point3(&Vec3::from_xyz(1.0, 1.0, 1.0)), Vec3::from_xyz(3.0, 4.0, 5.0));
+        assert_eq!(m.transform_vector3(&Vec3::from_xyz(1.0, 1.0, 1.0)), Vec3::splat(2.0));
+    }
+    #[test]
+    fn transform_rotation() {
+        assert_vec_approx_eq!(Mat4::from_rotation_x(FRAC_PI_2).transform_vector3(&Vec3::y_axis()), Vec3::z_axis(), epsilon = 1e-5);
+        assert_vec_approx_eq!(Mat4::from_rotation_y(FRAC_PI_2).transform_vector3(&Vec3::z_axis()), Vec3::x_axis(), epsilon = 1e-5);
+        assert_vec_approx_eq!(Mat4::from_rotation_z(FRAC_PI_2).transform_vector3(&Vec3::x_axis()), Vec3::y_axis(), epsilon = 1e-5);
+        let m = Mat4::from_axis_angle(&Vec3::from_xyz(0.0, 0.0, 2.0), FRAC_PI_2);
+        assert_vec_approx_eq!(m.transform_vector3(&Vec3::x_axis()), Vec3::y_axis(), epsilon = 1e-5);
+        let m = Mat4::from_axis_angle(&Vec3::splat(1.0), 1.0);
+        assert_vec_approx_eq!(m.transform_vector3(&Vec3::splat(1.0)), Vec3::splat(1.0), epsilon = 1e-5);
+    }
+    #[test]
+    fn transform_look_at() {
+        let eye = Vec3::from_xyz(0.0, 0.0, 5.0);
+        let rh = Mat4::look_at_rh(&eye, &Vec3::new(), &Vec3::y_axis());
+        assert_vec_approx_eq!(rh.transform_point3(&Vec3::new()), Vec3::from_xyz(0.0, 0.0, -5.0), epsilon = 1e-5);
+        assert_vec_approx_eq!(rh.transform_point3(&Vec3::x_axis()), Vec3::from_xyz(1.0, 0.0, -5.0), epsilon = 1e-5);
+        let lh = Mat4::look_at_lh(&eye, &Vec3::new(), &Vec3::y_axis());
+        assert_vec_approx_eq!(lh.transform_point3(&Vec3::new()), Vec3::from_xyz(0.0, 0.0, 5.0), epsilon = 1e-5);
+        assert_vec_approx_eq!(lh.transform_point3(&Vec3::x_axis()), Vec3::from_xyz(-1.0, 0.0, 5.0), epsilon = 1e-5);
+    }
+    #[test]
+    fn transform_perspective() {
+        let gl = Mat4::perspective_rh_gl(FRAC_PI_2, 1.0, 1.0, 10.0);
+        assert_vec_approx_eq!(gl.transform_point3(&Vec3::from_xyz(0.0, 0.0, -1.0)), Vec3::from_xyz(0.0, 0.0, -1.0), epsilon = 1e-5);
+        assert_vec_approx_eq!(gl.transform_point3(&Vec3::from_xyz(10.0, 0.0, -10.0)), Vec3::from_xyz(1.0, 0.0, 1.0), epsilon = 1e-5);
+        let zo = Mat4::perspective_rh(FRAC_PI_2, 1.0, 1.0, 10.0);
+        assert_vec_approx_eq!(zo.transform_point3(&Vec3::from_xyz(0.0, 0.0, -1.0)), Vec3::new(), epsilon = 1e-5);
+        assert_vec_approx_eq!(zo.transform_point3(&Vec3::from_xyz(0.0, 0.0, -10.0)), Vec3::z_axis(), epsilon = 1e-5);
+        let lh = Mat4::perspective_lh(FRAC_PI_2, 2.0, 1.0, 10.0);
+        assert_vec_approx_eq!(lh.transform_point3(&Vec3::from_xyz(2.0, 1.0, 1.0)), Vec3::from_xyz(1.0, 1.0, 0.0), epsilon = 1e-5);
+        let lh_gl = Mat4::perspective_lh_gl(FRAC_PI_2, 1.0, 1.0, 10.0);
+        assert_vec_approx_eq!(lh_gl.transform_point3(&Vec3::from_xyz(0.0, 0.0, 10.0)), Vec3::z_axis(), epsilon = 1e-5);
+    }
+    #[test]
+    fn transform_orthographic() {
+        let gl = Mat4::orthographic_rh_gl(-2.0, 2.0, -1.0, 1.0, 1.0, 3.0);
+        assert_vec_approx_eq!(gl.transform_point3(&Vec3::from_xyz(2.0, -1.0, -1.0)), Vec3::from_xyz(1.0, -1.0, -1.0), epsilon = 1e-5);
+        assert_vec_approx_eq!(gl.transform_point3(&Vec3::from_xyz(0.0, 0.0, -3.0)), Vec3::z_axis(), epsilon = 1e-5);
+        let rh = Mat4::orthographic_rh(-2.0, 2.0, -1.0, 1.0, 1.0, 3.0);
+        assert_vec_approx_eq!(rh.transform_point3(&Vec3::from_xyz(0.0, 0.0, -1.0)), Vec3::new(), epsilon = 1e-5);
+        let lh = Mat4::orthographic_lh(-2.0, 2.0, -1.0, 1.0, 1.0, 3.0);
+        assert_vec_approx_eq!(lh.transform_point3(&Vec3::from_xyz(0.0, 0.0, 3.0)), Vec3::z_axis(), epsilon = 1e-5);
+        let lh_gl = Mat4::orthographic_lh_gl(-2.0, 2.0, -1.0, 1.0, 1.0, 3.0);
+        assert_vec_approx_eq!(lh_gl.transform_point3(&Vec3::from_xyz(-2.0, 1.0, 1.0)), Vec3::from_xyz(-1.0, 1.0, -1.0), epsilon = 1e-5);
+        assert_vec_approx_eq!(lh_gl.transform_point3(&Vec3::from_xyz(0.0, 0.0, 3.0)), Vec3::z_axis(), epsilon = 1e-5);
+    }
+    #[test]
+    fn transform_point_zero_w() {
+        let gl = Mat4::perspective_rh_gl(FRAC_PI_2, 1.0, 1.0, 10.0);
+        // on the camera plane through the eye
+        assert_eq!(gl.try_transform_point3(&Vec3::from_xyz(1.0, 0.0, 0.0)), Err(MathError::ZeroW));
+        assert!(!gl.transform_point3(&Vec3::from_xyz(1.0, 0.0, 0.0)).is_finite());
+        assert_vec_approx_eq!(gl.try_transform_point3(&Vec3::from_xyz(0.0, 0.0, -1.0)).unwrap(), Vec3::from_xyz(0.0, 0.0, -1.0), epsilon = 1e-5);
+        let m = Mat4::from_translation(&Vec3::splat(1.0));
+        assert_eq!(m.try_transform_point3(&Vec3::new()), Ok(m.transform_point3(&Vec3::new())));
+    }
+}
@@ -0,0 +1,753 @@
+use std::ops::{Mul,Div,Add,Sub,Neg,AddAssign,SubAssign,MulAssign,DivAssign,Index,IndexMut};
+use paste::paste;
+use crate::{Scalar, Signed, Float, MathError};
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct VecN<T, const N: usize>(pub(crate) [T; N]);
+
+pub type Vec2 = VecN<f32, 2>;
+pub type Vec3 = VecN<f32, 3>;
+pub type Vec4 = VecN<f32, 4>;
+pub type DVec2 = VecN<f64, 2>;
+pub type DVec3 = VecN<f64, 3>;
+pub type DVec4 = VecN<f64, 4>;
+pub type IVec2 = VecN<i32, 2>;
+pub type IVec3 = VecN<i32, 3>;
+pub type IVec4 = VecN<i32, 4>;
+pub type UVec2 = VecN<u32, 2>;
+pub type UVec3 = VecN<u32, 3>;
+pub type UVec4 = VecN<u32, 4>;
+
+impl<T: Scalar, const N: usize> Default for VecN<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Scalar, const N: usize> VecN<T, N> {
+    pub fn new() -> Self {
+        VecN([T::ZERO; N])
+    }
+    pub fn axis(index: usize) -> Self {
+        let mut arr = [T::ZERO; N];
+        arr[index] = T::ONE;
+        VecN(arr)
+    }
+    pub fn splat(val: T) -> Self {
+        VecN([val; N])
+    }
+    pub fn as_array(&self) -> &[T; N] {
+        &self.0
+    }
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+    fn scalar_mult(&self, scale: T) -> Self {
+        VecN(self.0.map(|val| val * scale))
+    }
+    fn scalar_add(&self, num: T) -> Self {
+        VecN(self.0.map(|val| val + num))
+    }
+    fn scalar_div(&self, scale: T) -> Self {
+        VecN(self.0.map(|val| val / scale))
+    }
+    fn scalar_sub(&self, num: T) -> Self {
+        VecN(self.0.map(|val| val - num))
+    }
+    pub fn dot(&self, other: &Self) -> T {
+        self.0.iter().zip(other.0.iter()).map(|(a, b)| *a * *b).sum()
+    }
+}
+
+// methods which need a square root, so only the float families get them
+impl<T: Float, const N: usize> VecN<T, N> {
+    pub fn normalise(&self) -> Self {
+        let magnitude = self.magnitude();
+        // chose not to panic, a normalised empty vector is just an empty vector
+        if magnitude == T::ZERO {
+            return Self::new();
+        }
+
+        self.scalar_mult(T::ONE/magnitude)
+    }
+    pub fn magnitude(&self) -> T {
+        self.0.iter().fold(T::ZERO, |sum, val| sum + *val * *val).sqrt()
+    }
+    pub fn cos(&self, other: &Self) -> T {
+        // chose to panic since there is no meaning in cos(angle) of a vector with zero magnitude
+        assert!(self.magnitude() != T::ZERO && other.magnitude() != T::ZERO, "Magnitude of one of the vectors is zero");
+        self.dot(other)/(self.magnitude()*other.magnitude())
+    }
+    fn check_finite(&self) -> Result<(), MathError> {
+        if self.0.iter().any(|val| val.is_nan()) {
+            return Err(MathError::NaN);
+        }
+        if !self.0.iter().all(|val| val.is_finite()) {
+            return Err(MathError::NonFinite);
+        }
+        Ok(())
+    }
+    // the magnitude can still overflow for finite components, which is reported as non-finite too
+    fn try_magnitude(&self) -> Result<T, MathError> {
+        self.check_finite()?;
+        let magnitude = self.magnitude();
+        if magnitude == T::ZERO {
+            return Err(MathError::ZeroLength);
+        }
+        if !magnitude.is_finite() {
+            return Err(MathError::NonFinite);
+        }
+        Ok(magnitude)
+    }
+    pub fn try_normalise(&self) -> Result<Self, MathError> {
+        Ok(self.scalar_mult(T::ONE/self.try_magnitude()?))
+    }
+    pub fn try_cos(&self, other: &Self) -> Result<T, MathError> {
+        Ok(self.dot(other)/(self.try_magnitude()?*other.try_magnitude()?))
+    }
+    pub fn angle_between(&self, other: &Self) -> Result<T, MathError> {
+        let cos = self.try_cos(other)?;
+        // rounding can push cos just outside [-1, 1] where acos is NaN
+        let cos = if cos > T::ONE { T::ONE } else if cos < -T::ONE { -T::ONE } else { cos };
+        Ok(cos.acos())
+    }
+}
+
+impl<T: Signed> VecN<T, 3> {
+    pub fn cross(&self, other: &Self) -> Self {
+        VecN([
+            self.0[1] * other.0[2] - self.0[2] * other.0[1],
+            other.0[0] * self.0[2] - self.0[0] * other.0[2],
+            self.0[0] * other.0[1] - other.0[0] * self.0[1],
+        ])
+    }
+}
+
+impl<T: Signed> VecN<T, 2> {
+    // rotated a quarter turn counter-clockwise
+    pub fn perp(&self) -> Self {
+        VecN([-self.0[1], self.0[0]])
+    }
+    // the z of the 3d cross product, twice the signed area of the triangle (0, self, other)
+    pub fn perp_dot(&self, other: &Self) -> T {
+        self.0[0] * other.0[1] - self.0[1] * other.0[0]
+    }
+}
+
+impl<T: Float> VecN<T, 2> {
+    // angles in radians, counter-clockwise from the x axis
+    pub fn from_angle(angle: T) -> Self {
+        VecN([angle.cos(), angle.sin()])
+    }
+    pub fn to_angle(&self) -> T {
+        self.0[1].atan2(self.0[0])
+    }
+    // complex multiplication, by a unit vector such as from_angle is a pure rotation
+    pub fn rotate(&self, by: &Self) -> Self {
+        VecN([
+            self.0[0] * by.0[0] - self.0[1] * by.0[1],
+            self.0[1] * by.0[0] + self.0[0] * by.0[1],
+        ])
+    }
+    // unlike the 3d sin this keeps the sign, positive when other is counter-clockwise of self
+    pub fn sin(&self, other: &Self) -> T {
+        // chose to panic since there is no meaning in sin(angle) of a vector with zero magnitude
+        assert!(self.magnitude() != T::ZERO && other.magnitude() != T::ZERO, "Magnitude of one of the vectors is zero");
+        self.perp_dot(other)/(self.magnitude()*other.magnitude())
+    }
+    pub fn try_sin(&self, other: &Self) -> Result<T, MathError> {
+        Ok(self.perp_dot(other)/(self.try_magnitude()?*other.try_magnitude()?))
+    }
+}
+
+impl<T: Float> VecN<T, 3> {
+    pub fn sin(&self, other: &Self) -> T {
+        // chose to panic since there is no meaning in sin(angle) of a vector with zero magnitude
+        assert!(self.magnitude() != T::ZERO && other.magnitude() != T::ZERO, "Magnitude of one of the vectors is zero");
+        self.cross(other).magnitude()/(self.magnitude()*other.magnitude())
+    }
+    pub fn try_sin(&self, other: &Self) -> Result<T, MathError> {
+        Ok(self.cross(other).magnitude()/(self.try_magnitude()?*other.try_magnitude()?))
+    }
+}
+
+impl<T: Scalar, const N: usize> From<[T; N]> for VecN<T, N> {
+    fn from(arr: [T; N]) -> Self {
+        VecN(arr)
+    }
+}
+impl<T: Scalar, const N: usize> From<VecN<T, N>> for [T; N] {
+    fn from(vector: VecN<T, N>) -> Self {
+        vector.0
+    }
+}
+impl<T: Scalar, const N: usize> Index<usize> for VecN<T, N> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+impl<T: Scalar, const N: usize> IndexMut<usize> for VecN<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+impl<T: Scalar, const N: usize> Mul<T> for VecN<T, N> {
+    type Output = VecN<T, N>;
+    fn mul(self, scale: T) -> Self::Output {
+       self.scalar_mult(scale)
+    }
+}
+impl<T: Scalar, const N: usize> Add<T> for VecN<T, N> {
+    type Output = VecN<T, N>;
+    fn add(self, num: T) -> Self::Output {
+        self.scalar_add(num)
+    }
+}
+impl<T: Scalar, const N: usize> Div<T> for VecN<T, N> {
+    type Output = VecN<T, N>;
+    fn div(self, num: T) -> Self::Output {
+        self.scalar_div(num)
+    }
+}
+impl<T: Scalar, const N: usize> Sub<T> for VecN<T, N> {
+    type Output = VecN<T, N>;
+    fn sub(self, num: T) -> Self::Output {
+        self.scalar_sub(num)
+    }
+}
+// negation only makes sense for the signed scalars
+impl<T: Signed, const N: usize> Neg for VecN<T, N> {
+    type Output = VecN<T, N>;
+    fn neg(self) -> Self::Output {
+        VecN(self.0.map(|val| -val))
+    }
+}
+impl<T: Signed, const N: usize> Neg for &VecN<T, N> {
+    type Output = VecN<T, N>;
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+// the orphan rule stops `impl<T> Mul<VecN<T, N>> for T`, so the reversed operators are per scalar
+macro_rules! scalar_lhs {
+    ($($T:ident),*) => {
+        $(
+            impl<const N: usize> Mul<VecN<$T, N>> for $T {
+                type Output = VecN<$T, N>;
+                fn mul(self, vector: VecN<$T, N>) -> Self::Output {
+                    vector.scalar_mult(self)
+                }
+            }
+            impl<const N: usize> Add<VecN<$T, N>> for $T {
+                type Output = VecN<$T, N>;
+                fn add(self, vector: VecN<$T, N>) -> Self::Output {
+                    vector.scalar_add(self)
+                }
+            }
+        )*
+    };
+}
+
+scalar_lhs!(f32, f64, i32, u32);
+
+macro_rules! vector_op {
+    ($Op:ident, $op_fn:ident, $OpAssign:ident, $op_assign_fn:ident, $op:tt) => {
+        impl<T: Scalar, const N: usize> $Op<VecN<T, N>> for VecN<T, N> {
+            type Output = VecN<T, N>;
+            fn $op_fn(self, other: VecN<T, N>) -> Self::Output {
+                VecN(std::array::from_fn(|i| self.0[i] $op other.0[i]))
+            }
+        }
+        impl<T: Scalar, const N: usize> $Op<&VecN<T, N>> for &VecN<T, N> {
+            type Output = VecN<T, N>;
+            fn $op_fn(self, other: &VecN<T, N>) -> Self::Output {
+                *self $op *other
+            }
+        }
+        impl<T: Scalar, const N: usize> $Op<VecN<T, N>> for &VecN<T, N> {
+            type Output = VecN<T, N>;
+            fn $op_fn(self, other: VecN<T, N>) -> Self::Output {
+                *self $op other
+            }
+        }
+        impl<T: Scalar, const N: usize> $Op<&VecN<T, N>> for VecN<T, N> {
+            type Output = VecN<T, N>;
+            fn $op_fn(self, other: &VecN<T, N>) -> Self::Output {
+                self $op *other
+            }
+        }
+        impl<T: Scalar, const N: usize> $OpAssign<VecN<T, N>> for VecN<T, N> {
+            fn $op_assign_fn(&mut self, other: VecN<T, N>) {
+                self.0.iter_mut().zip(other.0).for_each(|(a, b)| *a = *a $op b);
+            }
+        }
+        impl<T: Scalar, const N: usize> $OpAssign<&VecN<T, N>> for VecN<T, N> {
+            fn $op_assign_fn(&mut self, other: &VecN<T, N>) {
+                self.$op_assign_fn(*other);
+            }
+        }
+    };
+}
+
+vector_op!(Add, add, AddAssign, add_assign, +);
+vector_op!(Sub, sub, SubAssign, sub_assign, -);
+// component-wise (hadamard) product, use dot for the inner product
+vector_op!(Mul, mul, MulAssign, mul_assign, *);
+vector_op!(Div, div, DivAssign, div_assign, /);
+
+macro_rules! axis_scalar {
+    ($axis:ident, $T:ident) => { $T };
+}
+pub(crate) use axis_scalar;
+
+// the named x()/y()/z()/w() api of the 2, 3 and 4 dimensional vectors
+macro_rules! named {
+    ($dim:expr, $from:ident, $(($axis_fn:ident, $axis:ident, $set_axis:ident, $axis_mut:ident => $index:expr)),*) => {
+        impl<T: Scalar> VecN<T, $dim> {
+            $(
+                pub fn $axis(&self) -> T {
+                    self.0[$index]
+                }
+                pub fn $set_axis(&mut self, val: T) {
+                    self.0[$index] = val;
+                }
+                pub fn $axis_mut(&mut self) -> &mut T {
+                    &mut self.0[$index]
+                }
+                pub fn $axis_fn() -> Self {
+                    Self::axis($index)
+                }
+            )*
+            pub fn $from($($axis: T),*) -> Self {
+                VecN([$($axis),*])
+            }
+        }
+        impl<T: Scalar> From<($(axis_scalar!($axis, T)),*)> for VecN<T, $dim> {
+            fn from(($($axis),*): ($(axis_scalar!($axis, T)),*)) -> Self {
+                VecN([$($axis),*])
+            }
+        }
+        impl<T: Scalar> From<VecN<T, $dim>> for ($(axis_scalar!($axis, T)),*) {
+            fn from(vector: VecN<T, $dim>) -> Self {
+                let [$($axis),*] = vector.0;
+                ($($axis),*)
+            }
+        }
+    };
+}
+
+named!(2, from_xy, (x_axis, x, set_x, x_mut => 0), (y_axis, y, set_y, y_mut => 1));
+named!(3, from_xyz, (x_axis, x, set_x, x_mut => 0), (y_axis, y, set_y, y_mut => 1), (z_axis, z, set_z, z_mut => 2));
+named!(4, from_xyzw, (x_axis, x, set_x, x_mut => 0), (y_axis, y, set_y, y_mut => 1), (z_axis, z, set_z, z_mut => 2), (w_axis, w, set_w, w_mut => 3));
+
+// generates every glsl style swizzle of length 2 to 4 by appending each component to the prefix,
+// the aligned vectors reuse it with their own return types
+macro_rules! swizzles {
+    ([$($header:tt)*], $rets:tt, $comps:tt) => {
+        $($header)* {
+            swizzles!(@extend [], $comps, $comps, $rets);
+        }
+    };
+    (@extend $prefix:tt, [], $comps:tt, $rets:tt) => {};
+    (@extend $prefix:tt, [$c:ident $ci:tt $(, $rest:ident $rest_i:tt)*], $comps:tt, $rets:tt) => {
+        swizzles!(@push $prefix, $c $ci, $comps, $rets);
+        swizzles!(@extend $prefix, [$($rest $rest_i),*], $comps, $rets);
+    };
+    (@push [$($p:tt)*], $c:ident $ci:tt, $comps:tt, $rets:tt) => {
+        swizzles!(@emit [$($p)* $c $ci], $comps, $rets);
+    };
+    (@emit [$a:ident $ai:tt], $comps:tt, $rets:tt) => {
+        swizzles!(@extend [$a $ai], $comps, $comps, $rets);
+    };
+    (@emit [$a:ident $ai:tt $b:ident $bi:tt], $comps:tt, [$Two:ty, $Three:ty, $Four:ty]) => {
+        paste! {
+            pub fn [<$a $b>](&self) -> $Two {
+                <$Two>::from([self.0[$ai], self.0[$bi]])
+            }
+        }
+        swizzles!(@extend [$a $ai $b $bi], $comps, $comps, [$Two, $Three, $Four]);
+    };
+    (@emit [$a:ident $ai:tt $b:ident $bi:tt $c:ident $ci:tt], $comps:tt, [$Two:ty, $Three:ty, $Four:ty]) => {
+        paste! {
+            pub fn [<$a $b $c>](&self) -> $Three {
+                <$Three>::from([self.0[$ai], self.0[$bi], self.0[$ci]])
+            }
+        }
+        swizzles!(@extend [$a $ai $b $bi $c $ci], $comps, $comps, [$Two, $Three, $Four]);
+    };
+    (@emit [$a:ident $ai:tt $b:ident $bi:tt $c:ident $ci:tt $d:ident $di:tt], $comps:tt, [$Two:ty, $Three:ty, $Four:ty]) => {
+        paste! {
+            pub fn [<$a $b $c $d>](&self) -> $Four {
+                <$Four>::from([self.0[$ai], self.0[$bi], self.0[$ci], self.0[$di]])
+            }
+        }
+    };
+}
+pub(crate) use swizzles;
+
+swizzles!([impl<T: Scalar> VecN<T, 2>], [VecN<T, 2>, VecN<T, 3>, VecN<T, 4>], [x 0, y 1]);
+swizzles!([impl<T: Scalar> VecN<T, 3>], [VecN<T, 2>, VecN<T, 3>, VecN<T, 4>], [x 0, y 1, z 2]);
+swizzles!([impl<T: Scalar> VecN<T, 4>], [VecN<T, 2>, VecN<T, 3>, VecN<T, 4>], [x 0, y 1, z 2, w 3]);
+
+impl<T: Scalar> VecN<T, 2> {
+    pub fn extend(&self, z: T) -> VecN<T, 3> {
+        VecN([self.0[0], self.0[1], z])
+    }
+    pub fn xy0(&self) -> VecN<T, 3> {
+        self.extend(T::ZERO)
+    }
+    pub fn xy1(&self) -> VecN<T, 3> {
+        self.extend(T::ONE)
+    }
+    pub fn xy00(&self) -> VecN<T, 4> {
+        self.xy0().extend(T::ZERO)
+    }
+    pub fn xy01(&self) -> VecN<T, 4> {
+        self.xy0().extend(T::ONE)
+    }
+    pub fn xy10(&self) -> VecN<T, 4> {
+        self.xy1().extend(T::ZERO)
+    }
+    pub fn xy11(&self) -> VecN<T, 4> {
+        self.xy1().extend(T::ONE)
+    }
+}
+impl<T: Scalar> VecN<T, 3> {
+    pub fn extend(&self, w: T) -> VecN<T, 4> {
+        VecN([self.0[0], self.0[1], self.0[2], w])
+    }
+    pub fn truncate(&self) -> VecN<T, 2> {
+        VecN([self.0[0], self.0[1]])
+    }
+    pub fn xyz0(&self) -> VecN<T, 4> {
+        self.extend(T::ZERO)
+    }
+    pub fn xyz1(&self) -> VecN<T, 4> {
+        self.extend(T::ONE)
+    }
+}
+impl<T: Scalar> VecN<T, 4> {
+    pub fn truncate(&self) -> VecN<T, 3> {
+        VecN([self.0[0], self.0[1], self.0[2]])
+    }
+}
+
+// homogeneous coordinates, points have w = 1 so translation applies and directions w = 0 so it doesn't
+impl<T: Scalar> VecN<T, 2> {
+    pub fn to_point3(&self) -> VecN<T, 3> {
+        self.extend(T::ONE)
+    }
+    pub fn to_direction3(&self) -> VecN<T, 3> {
+        self.extend(T::ZERO)
+    }
+}
+
+impl<T: Scalar> VecN<T, 3> {
+    pub fn to_point4(&self) -> VecN<T, 4> {
+        self.extend(T::ONE)
+    }
+    pub fn to_direction4(&self) -> VecN<T, 4> {
+        self.extend(T::ZERO)
+    }
+}
+
+impl<T: Float> VecN<T, 3> {
+    // perspective division, the last component is treated as w
+    pub fn project_to_vec2(&self) -> Result<VecN<T, 2>, MathError> {
+        let w = self.0[2];
+        if w == T::ZERO {
+            return Err(MathError::ZeroW);
+        }
+        Ok(self.truncate() / w)
+    }
+}
+
+impl<T: Float> VecN<T, 4> {
+    pub fn project_to_vec3(&self) -> Result<VecN<T, 3>, MathError> {
+        let w = self.0[3];
+        if w == T::ZERO {
+            return Err(MathError::ZeroW);
+        }
+        Ok(self.truncate() / w)
+    }
+}
+
+// lossy `as` casts between every family of the same dimension
+macro_rules! vector_casts {
+    ([$($Vec:ident),*], $targets:tt) => {
+        $( vector_casts!(@impl $Vec, $targets); )*
+    };
+    (@impl $Vec:ident, [$(($as_fn:ident, $Other:ident, $T:ident)),*]) => {
+        impl $Vec {
+            $(
+                #[allow(clippy::unnecessary_cast)]
+                pub fn $as_fn(&self) -> $Other {
+                    VecN(self.0.map(|val| val as $T))
+                }
+            )*
+        }
+    };
+}
+
+vector_casts!([Vec2, DVec2, IVec2, UVec2], [(as_vec2, Vec2, f32), (as_dvec2, DVec2, f64), (as_ivec2, IVec2, i32), (as_uvec2, UVec2, u32)]);
+vector_casts!([Vec3, DVec3, IVec3, UVec3], [(as_vec3, Vec3, f32), (as_dvec3, DVec3, f64), (as_ivec3, IVec3, i32), (as_uvec3, UVec3, u32)]);
+vector_casts!([Vec4, DVec4, IVec4, UVec4], [(as_vec4, Vec4, f32), (as_dvec4, DVec4, f64), (as_ivec4, IVec4, i32), (as_uvec4, UVec4, u32)]);
+
+// lossless conversions, every f32, i32 and u32 is exactly representable as an f64
+macro_rules! vector_widen {
+    ($($T:ident),*) => {
+        $(
+            impl<const N: usize> From<VecN<$T, N>> for VecN<f64, N> {
+                fn from(vector: VecN<$T, N>) -> Self {
+                    VecN(vector.0.map(|val| val.into()))
+                }
+            }
+        )*
+    };
+}
+
+vector_widen!(f32, i32, u32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_vec_approx_eq;
+
+    #[test]
+    fn vector_axis_identifier() {
+        let v = Vec4::from([1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(v.x(), 1.0);
+        assert_eq!(v.y(), 2.0);
+        assert_eq!(v.z(), 3.0);
+        assert_eq!(v.w(), 4.0);
+    }
+    #[test]
+    fn vector_axis() {
+        assert_eq!(Vec4::from([1.0, 0.0, 0.0, 0.0]), Vec4::x_axis());
+        assert_eq!(Vec4::from([0.0, 1.0, 0.0, 0.0]), Vec4::y_axis());
+        assert_eq!(Vec4::from([0.0, 0.0, 1.0, 0.0]), Vec4::z_axis());
+        assert_eq!(Vec4::from([0.0, 0.0, 0.0, 1.0]), Vec4::w_axis());
+    }
+    #[test]
+    fn vector_construction() {
+        assert_eq!(Vec3::from_xyz(1.0, 2.0, 3.0), Vec3::from([1.0, 2.0, 3.0]));
+        assert_eq!(Vec4::splat(2.0), Vec4::from([2.0; 4]));
+        assert_eq!(Vec2::from([1.0, 2.0]), Vec2::from_xy(1.0, 2.0));
+        assert_eq!(Vec2::from((1.0, 2.0)), Vec2::from_xy(1.0, 2.0));
+        let arr: [f32; 3] = Vec3::from_xyz(1.0, 2.0, 3.0).into();
+        assert_eq!(arr, [1.0, 2.0, 3.0]);
+        let tuple: (f32, f32, f32, f32) = Vec4::from_xyzw(1.0, 2.0, 3.0, 4.0).into();
+        assert_eq!(tuple, (1.0, 2.0, 3.0, 4.0));
+    }
+    #[test]
+    fn vector_component_access() {
+        let mut v = Vec3::from_xyz(1.0, 2.0, 3.0);
+        v.set_x(4.0);
+        *v.y_mut() += 1.0;
+        v[2] = 6.0;
+        assert_eq!(v[0], 4.0);
+        assert_eq!(v.as_array(), &[4.0, 3.0, 6.0]);
+        assert_eq!(v.as_slice(), &[4.0, 3.0, 6.0]);
+    }
+    #[test]
+    fn vector_swizzle() {
+        let v = Vec4::from_xyzw(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(v.xy(), Vec2::from_xy(1.0, 2.0));
+        assert_eq!(v.wzx(), Vec3::from_xyz(4.0, 3.0, 1.0));
+        assert_eq!(v.xxyy(), Vec4::from_xyzw(1.0, 1.0, 2.0, 2.0));
+        assert_eq!(v.xyz(), v.truncate());
+        assert_eq!(Vec2::from_xy(1.0, 2.0).yyyx(), Vec4::from_xyzw(2.0, 2.0, 2.0, 1.0));
+        assert_eq!(Vec3::from_xyz(1.0, 2.0, 3.0).zyx(), Vec3::from_xyz(3.0, 2.0, 1.0));
+    }
+    #[test]
+    fn vector_extend_truncate() {
+        let v = Vec2::from_xy(1.0, 2.0);
+        assert_eq!(v.extend(3.0), Vec3::from_xyz(1.0, 2.0, 3.0));
+        assert_eq!(v.xy0(), Vec3::from_xyz(1.0, 2.0, 0.0));
+        assert_eq!(v.xy01(), Vec4::from_xyzw(1.0, 2.0, 0.0, 1.0));
+        assert_eq!(v.xy1().xyz1().truncate().truncate(), v);
+    }
+    #[test]
+    fn vector_families() {
+        let v = IVec3::from_xyz(1, -2, 3) + IVec3::splat(2);
+        assert_eq!(v, IVec3::from_xyz(3, 0, 5));
+        assert_eq!(v.dot(&IVec3::z_axis()), 5);
+        assert_eq!(UVec2::from_xy(3, 4) * 2, UVec2::from_xy(6, 8));
+        assert_eq!(DVec2::from_xy(3.0, 4.0).magnitude(), 5.0);
+        assert_eq!(IVec3::from_xyz(1, 2, 3).cross(&IVec3::from_xyz(3, 2, 1)), IVec3::from_xyz(-4, 8, -4));
+    }
+    #[test]
+    fn vector_casts() {
+        assert_eq!(Vec3::from_xyz(1.5, -2.5, 3.0).as_ivec3(), IVec3::from_xyz(1, -2, 3));
+        assert_eq!(IVec2::from_xy(-1, 2).as_uvec2(), UVec2::from_xy(u32::MAX, 2));
+        assert_eq!(DVec4::from(UVec4::splat(7)), DVec4::splat(7.0));
+        assert_eq!(DVec3::from(Vec3::from_xyz(0.5, 1.0, 2.0)).as_vec3(), Vec3::from_xyz(0.5, 1.0, 2.0));
+    }
+    #[test]
+    fn vector_n() {
+        let v = VecN::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let v2 = VecN::<f64, 6>::axis(5) * 2.0;
+        assert_eq!(v.dot(&v2), 12.0);
+        assert_eq!((v - v).magnitude(), 0.0);
+        assert_eq!(VecN::<f32, 16>::splat(1.0).magnitude(), 4.0);
+        assert_eq!(VecN::<f32, 6>::axis(2).normalise().cos(&VecN::axis(2)), 1.0);
+    }
+    #[test]
+    fn vector_normalise() {
+        assert_eq!(Vec2::from([3.0, 4.0]).normalise(), Vec2::from([0.6, 0.8]));
+        assert_eq!(Vec2::new().normalise(), Vec2::new());
+    }
+    #[test]
+    fn vector_scalar_mult() {
+        assert_eq!(Vec2::from([3.0, 4.0]) * 2.0, Vec2::from([6.0, 8.0]));
+        assert_eq!(2.0 * Vec2::from([3.0, 4.0]), Vec2::from([6.0, 8.0]));
+    }
+    #[test]
+    fn vector_scalar_div() {
+        assert_eq!(Vec2::from([2.0, 3.0]) / 2.0, Vec2::from([1.0, 1.5]));
+    }
+    #[test]
+    fn vector_scalar_add() {
+        assert_eq!(Vec2::from([3.0, 4.0]) + 2.0, Vec2::from([5.0, 6.0]));
+        assert_eq!(2.0 + Vec2::from([3.0, 4.0]), Vec2::from([5.0, 6.0]));
+    }
+    #[test]
+    fn vector_scalar_sub() {
+        assert_eq!(Vec2::from([3.0, 4.0]) - 2.0, Vec2::from([1.0, 2.0]));
+    }
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn vector_add() {
+        let mut v = Vec3::from([1.0, 2.0, 3.0]) + Vec3::from([3.0, 2.0, 1.0]);
+        assert_eq!(v, Vec3::from([4.0, 4.0, 4.0]));
+        assert_eq!(&v + &Vec3::from([1.0, 0.0, 0.0]), Vec3::from([5.0, 4.0, 4.0]));
+        v += &Vec3::from([1.0, 1.0, 1.0]);
+        assert_eq!(v, Vec3::from([5.0, 5.0, 5.0]));
+    }
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn vector_sub() {
+        let mut v = Vec3::from([1.0, 2.0, 3.0]) - &Vec3::from([3.0, 2.0, 1.0]);
+        assert_eq!(v, Vec3::from([-2.0, 0.0, 2.0]));
+        v -= Vec3::from([1.0, 1.0, 1.0]);
+        assert_eq!(v, Vec3::from([-3.0, -1.0, 1.0]));
+    }
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn vector_mul() {
+        let mut v = &Vec2::from([3.0, 4.0]) * Vec2::from([2.0, 0.5]);
+        assert_eq!(v, Vec2::from([6.0, 2.0]));
+        v *= Vec2::from([0.5, 2.0]);
+        assert_eq!(v, Vec2::from([3.0, 4.0]));
+    }
+    #[test]
+    fn vector_div() {
+        let mut v = Vec4::from([2.0, 4.0, 6.0, 8.0]) / Vec4::from([2.0, 2.0, 3.0, 4.0]);
+        assert_eq!(v, Vec4::from([1.0, 2.0, 2.0, 2.0]));
+        v /= &Vec4::from([1.0, 2.0, 2.0, 2.0]);
+        assert_eq!(v, Vec4::from([1.0; 4]));
+    }
+    #[test]
+    fn vector_neg() {
+        assert_eq!(-Vec3::from([1.0, -2.0, 3.0]), Vec3::from([-1.0, 2.0, -3.0]));
+        assert_eq!(-&Vec2::from([1.0, 0.0]), Vec2::from([-1.0, -0.0]));
+    }
+    #[test]
+    fn vector_magnitude() {
+        assert_eq!(Vec2::from([3.0, 4.0]).magnitude(), 5.0);
+    }
+    #[test]
+    fn vector_dot() {
+        let v = Vec4::from([1.0, 2.0, 3.0, 4.0]);
+        let v2 = Vec4::from([4.0, 3.0, 2.0, 1.0]);
+        assert_eq!(v.dot(&v2), 20.0);
+    }
+    #[test]
+    fn vector_cross() {
+        let v = Vec3::from([1.0, 2.0, 3.0]);
+        let v2 = Vec3::from([3.0, 2.0, 1.0]);
+        assert_eq!(v.cross(&v2), Vec3::from([-4.0, 8.0, -4.0]));
+    }
+    #[test]
+    fn vector_cos() {
+        let v = Vec3::from([1.0, 2.0, 3.0]);
+        let v2 = Vec3::from([3.0, 2.0, 1.0]);
+        assert_vec_approx_eq!(v.cos(&v2), 0.7142857)
+    }
+    #[test]
+    #[should_panic = "Magnitude of one of the vectors is zero"]
+    fn vector_cos_zero() {
+        let v = Vec3::from([0.0; 3]);
+        let v2 = Vec3::from([3.0, 2.0, 1.0]);
+        v.cos(&v2);
+    }
+    #[test]
+    fn vector_sin() {
+        let v = Vec3::from([1.0, 2.0, 3.0]);
+        let v2 = Vec3::from([3.0, 2.0, 1.0]);
+        assert_vec_approx_eq!(v.sin(&v2), 0.6998542)
+    }
+    #[test]
+    fn vector_try_cos_sin() {
+        let v = Vec3::from([1.0, 2.0, 3.0]);
+        let v2 = Vec3::from([3.0, 2.0, 1.0]);
+        assert_eq!(v.try_cos(&v2), Ok(v.cos(&v2)));
+        assert_eq!(v.try_sin(&v2), Ok(v.sin(&v2)));
+        assert_eq!(Vec3::new().try_cos(&v2), Err(MathError::ZeroLength));
+        assert_eq!(v.try_sin(&Vec3::new()), Err(MathError::ZeroLength));
+        assert_eq!(v.try_cos(&Vec3::from([f32::NAN, 0.0, 0.0])), Err(MathError::NaN));
+        assert_eq!(Vec3::from([f32::INFINITY, 0.0, 0.0]).try_sin(&v2), Err(MathError::NonFinite));
+    }
+    #[test]
+    fn vector_try_normalise() {
+        assert_eq!(Vec2::from([3.0, 4.0]).try_normalise(), Ok(Vec2::from([0.6, 0.8])));
+        assert_eq!(Vec2::new().try_normalise(), Err(MathError::ZeroLength));
+        assert_eq!(Vec2::from([f32::MAX, f32::MAX]).try_normalise(), Err(MathError::NonFinite));
+    }
+    #[test]
+    fn vector_angle_between() {
+        assert_eq!(Vec2::x_axis().angle_between(&Vec2::y_axis()), Ok(std::f32::consts::FRAC_PI_2));
+        assert_eq!(Vec3::splat(1.0).angle_between(&Vec3::splat(2.0)), Ok(0.0));
+        assert_eq!(Vec4::new().angle_between(&Vec4::x_axis()), Err(MathError::ZeroLength));
+    }
+    #[test]
+    #[should_panic = "Magnitude of one of the vectors is zero"]
+    fn vector_sin_zero() {
+        let v = Vec3::from([0.0; 3]);
+        let v2 = Vec3::from([3.0, 2.0, 1.0]);
+        v.sin(&v2);
+    }
+    #[test]
+    fn vector_2d() {
+        let v = Vec2::from_xy(2.0, 1.0);
+        assert_eq!(v.perp(), Vec2::from_xy(-1.0, 2.0));
+        assert_eq!(v.perp_dot(&Vec2::from_xy(0.0, 3.0)), 6.0);
+        assert_eq!(IVec2::from_xy(1, 0).perp_dot(&IVec2::from_xy(0, 1)), 1);
+        assert_vec_approx_eq!(Vec2::from_angle(std::f32::consts::FRAC_PI_2), Vec2::y_axis());
+        assert_vec_approx_eq!(Vec2::from_xy(-1.0, -1.0).to_angle(), -3.0 * std::f32::consts::FRAC_PI_4);
+        assert_vec_approx_eq!(v.rotate(&Vec2::from_angle(std::f32::consts::PI)), -v);
+        assert_vec_approx_eq!(v.rotate(&Vec2::y_axis()), v.perp());
+    }
+    #[test]
+    fn vector_2d_sin() {
+        let (a, b) = (Vec2::from_xy(1.0, 0.0), Vec2::from_xy(1.0, 1.0));
+        assert_vec_approx_eq!(a.sin(&b), std::f32::consts::FRAC_1_SQRT_2);
+        assert_vec_approx_eq!(b.sin(&a), -std::f32::consts::FRAC_1_SQRT_2);
+        assert_eq!(a.try_sin(&Vec2::new()), Err(MathError::ZeroLength));
+    }
+    #[test]
+    fn vector_homogeneous() {
+        let v = Vec3::from_xyz(1.0, 2.0, 3.0);
+        assert_eq!(v.to_point4(), Vec4::from_xyzw(1.0, 2.0, 3.0, 1.0));
+        assert_eq!(v.to_direction4(), Vec4::from_xyzw(1.0, 2.0, 3.0, 0.0));
+        assert_eq!((v.to_point4() * 2.0).project_to_vec3(), Ok(v));
+        assert_eq!(v.to_direction4().project_to_vec3(), Err(MathError::ZeroW));
+        let p = Vec2::from_xy(1.0, 2.0);
+        assert_eq!((p.to_point3(), p.to_direction3()), (Vec3::from_xyz(1.0, 2.0, 1.0), Vec3::from_xyz(1.0, 2.0, 0.0)));
+        assert_eq!(Vec3::from_xyz(2.0, 4.0, 2.0).project_to_vec2(), Ok(p));
+        assert_eq!(p.to_direction3().project_to_vec2(), Err(MathError::ZeroW));
+    }
+}